@@ -4,13 +4,33 @@
 //! signed/unsigned/floating-point numbers, along with bitwise and arithmetic operations.
 //! The types are implemented using arrays of `Bit` and provide operator overloading
 //! for arithmetic and logic, as well as conversion to/from primitive Rust types.
-
-use std::{
+//!
+//! # Features
+//!
+//! - `std` (default): float rounding and `sqrt` on `R32`/`R64`; implies `alloc`.
+//!   Without it the crate is `#![no_std]`.
+//! - `alloc`: the dynamic `Bits` container, `ripple_add`, `to_grouped_binary` and
+//!   the `Display`/`Binary` impls of `Nuint`.
+//! - `serde`: `Serialize`/`Deserialize` using the primitive representation.
+//! - `rand`: `Standard` sampling for the integer and float types.
+//! - `arbitrary`: `Arbitrary` for the integer types; implies `std`.
+
+// Indexed loops over the bit arrays mirror the hardware being modelled.
+#![allow(clippy::needless_range_loop)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+use core::{
     cmp::Ordering,
     fmt::{self, Display, Formatter},
+    iter::{Product, Sum},
     ops::{
-        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Not, Rem, RemAssign, Shl, ShlAssign, Shr,
-        ShrAssign, Sub, SubAssign,
+        Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign,
+        Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
     },
     str::FromStr,
 };
@@ -21,6 +41,10 @@ pub trait BitCount {
     fn count_ones(&self) -> u32;
     /// Returns the number of zeros in the bit sequence.
     fn count_zeros(&self) -> u32;
+    /// Orders by the number of ones, ignoring numeric value.
+    fn cmp_popcount(&self, other: &Self) -> Ordering {
+        self.count_ones().cmp(&other.count_ones())
+    }
 }
 
 /// Trait for rotating bits left and right.
@@ -35,11 +59,193 @@ pub trait BitwiseRotate {
 pub trait BitwiseReverse {
     /// Reverses the order of bits in the sequence.
     fn reverse_bits(&mut self);
+
+    /// Returns a copy with the order of bits reversed, leaving `self` unchanged.
+    fn reversed_bits(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut reversed = self.clone();
+        reversed.reverse_bits();
+        reversed
+    }
+}
+
+/// Trait for scanning runs of zero or one bits from either end of a bit sequence.
+pub trait BitScan {
+    /// Returns the number of zero bits before the most significant one.
+    fn leading_zeros(&self) -> u32;
+    /// Returns the number of zero bits after the least significant one.
+    fn trailing_zeros(&self) -> u32;
+    /// Returns the number of consecutive one bits starting from the most significant bit.
+    fn leading_ones(&self) -> u32;
+    /// Returns the number of consecutive one bits starting from the least significant bit.
+    fn trailing_ones(&self) -> u32;
+}
+
+/// Trait for the parity of the number of set bits.
+pub trait Parity {
+    /// Returns `Bit::One` if an odd number of bits are set, `Bit::Zero` otherwise.
+    fn parity(&self) -> Bit;
+
+    /// Returns `Bool::True` if an even number of bits are set.
+    fn has_even_parity(&self) -> Bool {
+        self.parity().is_zero()
+    }
+}
+
+/// Common surface shared by the integer types, for writing generic code.
+///
+/// Multiplication is exposed through `*=` and `wrapping_mul` rather than a `Mul`
+/// bound, because `N16 * N16` widens to `N32`.
+pub trait KarccInt:
+    Copy
+    + Eq
+    + Ord
+    + fmt::Debug
+    + Display
+    + Default
+    + BitCount
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{
+    /// The primitive integer with the same width and signedness.
+    type Primitive: Copy + From<Self> + Into<Self>;
+
+    /// The value zero.
+    const ZERO: Self;
+    /// The value one.
+    const ONE: Self;
+    /// The smallest representable value.
+    const MIN: Self;
+    /// The largest representable value.
+    const MAX: Self;
+
+    /// Addition wrapping around at the numeric bounds.
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Subtraction wrapping around at the numeric bounds.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Multiplication wrapping around at the numeric bounds.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Addition returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Subtraction returning `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Multiplication returning `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+/// Error returned when parsing a binary or hexadecimal digit string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBitsError {
+    /// The input has more significant digits than fit in the target type.
+    TooLong { len: usize },
+    /// The input has fewer digits than a fixed-width format requires.
+    TooShort { len: usize },
+    /// The input contains a character that is not a digit in the expected base.
+    InvalidChar { ch: char, index: usize },
+    /// The input contains no digits, e.g. `""` or a bare `"0x"` prefix.
+    Empty,
+}
+
+impl Display for ParseBitsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseBitsError::TooLong { len } => {
+                write!(f, "input of {} digits is too long for the target type", len)
+            }
+            ParseBitsError::TooShort { len } => {
+                write!(
+                    f,
+                    "input of {} digits is too short for the target type",
+                    len
+                )
+            }
+            ParseBitsError::InvalidChar { ch, index } => {
+                write!(f, "invalid digit {:?} at index {}", ch, index)
+            }
+            ParseBitsError::Empty => write!(f, "no digits in input"),
+        }
+    }
 }
 
-/// Represents a single bit (0 or 1).
+impl core::error::Error for ParseBitsError {}
+
+/// Error returned when a value does not fit in the target type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversionError;
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "value out of range for the target type")
+    }
+}
+
+impl core::error::Error for ConversionError {}
+
+/// Parses an MSB-first binary digit string into an LSB-first bit array.
+/// At least one digit is required.
+fn parse_bits<const N: usize>(s: &str) -> Result<[Bit; N], ParseBitsError> {
+    if s.is_empty() {
+        return Err(ParseBitsError::Empty);
+    }
+    let len = s.chars().count();
+    if len > N {
+        return Err(ParseBitsError::TooLong { len });
+    }
+    let mut bits = [Bit::Zero; N];
+    for (index, ch) in s.chars().enumerate() {
+        bits[len - 1 - index] = match ch {
+            '0' => Bit::Zero,
+            '1' => Bit::One,
+            _ => return Err(ParseBitsError::InvalidChar { ch, index }),
+        };
+    }
+    Ok(bits)
+}
+
+/// Parses a hexadecimal string with an optional `0x`/`0X` prefix into LSB-first bits.
+/// Leading zeros are accepted as long as the value fits in `N` bits; at least one
+/// digit is required.
+fn parse_hex_bits<const N: usize>(s: &str) -> Result<[Bit; N], ParseBitsError> {
+    let (digits, offset) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(rest) => (rest, 2),
+        None => (s, 0),
+    };
+    if digits.is_empty() {
+        return Err(ParseBitsError::Empty);
+    }
+    let len = digits.chars().count();
+    let mut bits = [Bit::Zero; N];
+    for (index, ch) in digits.chars().enumerate() {
+        let Some(value) = ch.to_digit(16) else {
+            return Err(ParseBitsError::InvalidChar {
+                ch,
+                index: index + offset,
+            });
+        };
+        let shift = 4 * (len - 1 - index);
+        for i in 0..4 {
+            if value & (1 << i) != 0 {
+                if shift + i >= N {
+                    return Err(ParseBitsError::TooLong { len });
+                }
+                bits[shift + i] = Bit::One;
+            }
+        }
+    }
+    Ok(bits)
+}
+
+/// Represents a single bit (0 or 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Bit {
+    #[default]
     Zero,
     One,
 }
@@ -95,6 +301,23 @@ impl Bit {
         }
     }
 
+    /// Returns `'0'` or `'1'`, matching `Display`.
+    pub fn to_char(&self) -> char {
+        match self {
+            Bit::Zero => '0',
+            Bit::One => '1',
+        }
+    }
+
+    /// Parses `'0'` or `'1'`. Any other character is reported at index 0.
+    pub fn from_char(c: char) -> Result<Bit, ParseBitsError> {
+        match c {
+            '0' => Ok(Bit::Zero),
+            '1' => Ok(Bit::One),
+            _ => Err(ParseBitsError::InvalidChar { ch: c, index: 0 }),
+        }
+    }
+
     /// Returns `Bool::True` if the bit is zero.
     pub fn is_zero(&self) -> Bool {
         match self {
@@ -151,6 +374,18 @@ impl Bit {
     }
 }
 
+impl From<Bit> for Bool {
+    fn from(value: Bit) -> Self {
+        value.as_bool()
+    }
+}
+
+impl From<Bool> for Bit {
+    fn from(value: Bool) -> Self {
+        Bit::from_bool(value)
+    }
+}
+
 impl Rem for Bit {
     type Output = Bit;
 
@@ -176,7 +411,7 @@ impl AddAssign for Bit {
     }
 }
 
-impl std::ops::BitXor for Bit {
+impl core::ops::BitXor for Bit {
     type Output = Bit;
 
     fn bitxor(self, other: Self) -> Self::Output {
@@ -184,19 +419,19 @@ impl std::ops::BitXor for Bit {
     }
 }
 
-impl std::ops::BitAndAssign for Bit {
+impl core::ops::BitAndAssign for Bit {
     fn bitand_assign(&mut self, other: Self) {
         *self = self.and(&other);
     }
 }
 
-impl std::ops::BitOrAssign for Bit {
+impl core::ops::BitOrAssign for Bit {
     fn bitor_assign(&mut self, other: Self) {
         *self = self.or(&other);
     }
 }
 
-impl std::ops::BitXorAssign for Bit {
+impl core::ops::BitXorAssign for Bit {
     fn bitxor_assign(&mut self, other: Self) {
         *self = self.xor(&other);
     }
@@ -213,7 +448,7 @@ impl Not for Bit {
     }
 }
 
-impl std::ops::BitOr for Bit {
+impl core::ops::BitOr for Bit {
     type Output = Bit;
 
     fn bitor(self, other: Self) -> Self {
@@ -236,14 +471,35 @@ impl Bit {
     pub fn xnor(&self, other: &Bit) -> Bit {
         self.xor(other).not()
     }
+
+    /// 2:1 multiplexer: returns `a` when `sel` is one, otherwise `b`.
+    pub fn mux(sel: Bit, a: Bit, b: Bit) -> Bit {
+        (sel & a) | (!sel & b)
+    }
+
+    /// Half adder, returning `(sum, carry)`.
+    pub fn half_adder(a: Bit, b: Bit) -> (Bit, Bit) {
+        (a ^ b, a & b)
+    }
+
+    /// Full adder, returning `(sum, carry_out)`.
+    pub fn full_add(a: Bit, b: Bit, carry: Bit) -> (Bit, Bit) {
+        full_adder(a, b, carry)
+    }
+
+    /// Full subtractor computing `a - b - borrow`, returning `(difference, borrow_out)`.
+    pub fn full_sub(a: Bit, b: Bit, borrow: Bit) -> (Bit, Bit) {
+        full_subtractor(a, b, borrow)
+    }
 }
 
 /// Represents a byte (8 bits).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Byte {
     bits: [Bit; 8],
 }
 
+/// Formats the byte as its eight binary digits, MSB first. Use `value` for the number.
 impl Display for Byte {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         for bit in self.bits.iter().rev() {
@@ -253,12 +509,55 @@ impl Display for Byte {
     }
 }
 
+/// Always prints all eight digits, MSB first; width and `#` flags still apply.
 impl fmt::Binary for Byte {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for bit in self.bits.iter().rev() {
-            write!(f, "{}", bit)?;
+        let value = u8::from(N8::from(*self));
+        let mut digits = [b'0'; 8];
+        for i in 0..8 {
+            if value & (1 << (7 - i)) != 0 {
+                digits[i] = b'1';
+            }
         }
-        Ok(())
+        f.pad_integral(true, "0b", core::str::from_utf8(&digits).unwrap())
+    }
+}
+
+impl Byte {
+    /// Parses exactly 8 `'0'`/`'1'` characters, most significant bit first, so the
+    /// result round-trips with `Display`. Iteration stops at a ninth character,
+    /// which is reported as `TooLong { len: 9 }`.
+    pub fn from_chars<I: IntoIterator<Item = char>>(chars: I) -> Result<Byte, ParseBitsError> {
+        let mut bits = [Bit::Zero; 8];
+        let mut len = 0;
+        for (index, ch) in chars.into_iter().enumerate() {
+            if index == 8 {
+                return Err(ParseBitsError::TooLong { len: 9 });
+            }
+            bits[7 - index] =
+                Bit::from_char(ch).map_err(|_| ParseBitsError::InvalidChar { ch, index })?;
+            len += 1;
+        }
+        match len {
+            0 => Err(ParseBitsError::Empty),
+            8 => Ok(Byte { bits }),
+            _ => Err(ParseBitsError::TooShort { len }),
+        }
+    }
+}
+
+/// Parses an MSB-first binary digit string of exactly 8 digits, the inverse of
+/// `Display`.
+impl TryFrom<&str> for Byte {
+    type Error = ParseBitsError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let bits = parse_bits(value)?;
+        let len = value.chars().count();
+        if len < 8 {
+            return Err(ParseBitsError::TooShort { len });
+        }
+        Ok(Byte { bits })
     }
 }
 
@@ -283,6 +582,11 @@ impl Byte {
         &self.bits
     }
 
+    /// Returns the numeric value of the byte.
+    pub fn value(&self) -> u8 {
+        u8::from(N8::from(*self))
+    }
+
     /// Inverts all bits in the byte.
     pub fn invert(&mut self) {
         for bit in &mut self.bits {
@@ -291,7 +595,7 @@ impl Byte {
     }
 }
 
-impl std::ops::BitAnd for Bit {
+impl core::ops::BitAnd for Bit {
     type Output = Self;
 
     fn bitand(self, other: Self) -> Self {
@@ -299,7 +603,7 @@ impl std::ops::BitAnd for Bit {
     }
 }
 
-impl std::ops::BitAnd for Byte {
+impl core::ops::BitAnd for Byte {
     type Output = Self;
 
     fn bitand(self, other: Self) -> Self {
@@ -321,6 +625,19 @@ impl FromIterator<Bit> for Byte {
     }
 }
 
+/// Interprets the array least significant bit first: element 0 becomes bit 0.
+impl From<[bool; 8]> for Byte {
+    fn from(value: [bool; 8]) -> Self {
+        let mut bits = [Bit::Zero; 8];
+        for (i, &b) in value.iter().enumerate() {
+            if b {
+                bits[i] = Bit::One;
+            }
+        }
+        Byte { bits }
+    }
+}
+
 impl Byte {
     /// Bitwise AND with another byte.
     pub fn and(&self, other: &Byte) -> Byte {
@@ -348,6 +665,15 @@ impl Byte {
         }
         Byte { bits: result }
     }
+
+    /// Byte-wide 2:1 multiplexer: returns `a` when `sel` is one, otherwise `b`.
+    pub fn mux(sel: Bit, a: Byte, b: Byte) -> Byte {
+        let mut result = [Bit::Zero; 8];
+        for i in 0..8 {
+            result[i] = Bit::mux(sel, a.bits[i], b.bits[i]);
+        }
+        Byte { bits: result }
+    }
 }
 
 impl Not for Byte {
@@ -416,7 +742,25 @@ impl Byte {
     }
 }
 
-impl std::ops::BitOr for Byte {
+impl BitwiseRotate for Byte {
+    fn rotate_left(&mut self, n: u32) {
+        *self = Byte::rotate_left(self, n as usize);
+    }
+    fn rotate_right(&mut self, n: u32) {
+        *self = Byte::rotate_right(self, n as usize);
+    }
+}
+
+impl BitCount for Byte {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        8 - self.count_ones()
+    }
+}
+
+impl core::ops::BitOr for Byte {
     type Output = Byte;
 
     fn bitor(self, other: Self) -> Self {
@@ -428,10 +772,61 @@ impl std::ops::BitOr for Byte {
     }
 }
 
+impl Index<usize> for Byte {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl IndexMut<usize> for Byte {
+    fn index_mut(&mut self, index: usize) -> &mut Bit {
+        &mut self.bits[index]
+    }
+}
+
+impl Byte {
+    /// Returns an iterator over the bits, least significant bit first.
+    pub fn iter_bits(&self) -> core::array::IntoIter<Bit, 8> {
+        self.bits.into_iter()
+    }
+}
+
+impl IntoIterator for Byte {
+    type Item = Bit;
+    type IntoIter = core::array::IntoIter<Bit, 8>;
+
+    /// Yields the bits least significant first, matching `FromIterator`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits.into_iter()
+    }
+}
+
+impl Byte {
+    /// Packs two nibbles into a byte: `high` fills bits 4–7 and `low` fills bits 0–3.
+    pub fn from_nibbles(high: Nibble, low: Nibble) -> Byte {
+        let mut bits = [Bit::Zero; 8];
+        bits[..4].copy_from_slice(&low.bits);
+        bits[4..].copy_from_slice(&high.bits);
+        Byte { bits }
+    }
+
+    /// Splits the byte into `(high, low)` nibbles, the inverse of `from_nibbles`.
+    pub fn to_nibbles(self) -> (Nibble, Nibble) {
+        let mut high = [Bit::Zero; 4];
+        let mut low = [Bit::Zero; 4];
+        low.copy_from_slice(&self.bits[..4]);
+        high.copy_from_slice(&self.bits[4..]);
+        (Nibble { bits: high }, Nibble { bits: low })
+    }
+}
+
 /// Boolean type for logic operations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Bool {
     True,
+    #[default]
     False,
 }
 
@@ -449,6 +844,23 @@ impl Bool {
     pub fn new(value: bool) -> Bool {
         if value { Bool::True } else { Bool::False }
     }
+
+    /// Converts the `Bool` to a primitive `bool`.
+    pub fn to_bool(&self) -> bool {
+        matches!(self, Bool::True)
+    }
+}
+
+impl From<bool> for Bool {
+    fn from(value: bool) -> Self {
+        Bool::new(value)
+    }
+}
+
+impl From<Bool> for bool {
+    fn from(value: Bool) -> Self {
+        value.to_bool()
+    }
 }
 
 impl PartialEq<bool> for Bool {
@@ -469,7 +881,7 @@ impl PartialEq<Bool> for bool {
     }
 }
 
-impl std::ops::BitAnd<bool> for Bool {
+impl core::ops::BitAnd<bool> for Bool {
     type Output = Bool;
 
     fn bitand(self, rhs: bool) -> Self::Output {
@@ -481,7 +893,7 @@ impl std::ops::BitAnd<bool> for Bool {
     }
 }
 
-impl std::ops::BitOr<bool> for Bool {
+impl core::ops::BitOr<bool> for Bool {
     type Output = Bool;
 
     fn bitor(self, rhs: bool) -> Self::Output {
@@ -493,7 +905,7 @@ impl std::ops::BitOr<bool> for Bool {
     }
 }
 
-impl std::ops::BitXor<bool> for Bool {
+impl core::ops::BitXor<bool> for Bool {
     type Output = Bool;
 
     fn bitxor(self, rhs: bool) -> Self::Output {
@@ -533,6 +945,34 @@ impl Bool {
             _ => Bool::False,
         }
     }
+
+    /// Logical NAND.
+    pub fn nand(&self, other: &Bool) -> Bool {
+        self.and(other).not()
+    }
+
+    /// Logical NOR.
+    pub fn nor(&self, other: &Bool) -> Bool {
+        self.or(other).not()
+    }
+
+    /// Logical XNOR (equivalence).
+    pub fn xnor(&self, other: &Bool) -> Bool {
+        self.xor(other).not()
+    }
+
+    /// Logical implication, `!self || other`.
+    pub fn implies(&self, other: &Bool) -> Bool {
+        self.not().or(other)
+    }
+
+    /// Returns `if_true` when `self` is `True`, otherwise `if_false`.
+    pub fn select<T>(&self, if_true: T, if_false: T) -> T {
+        match self {
+            Bool::True => if_true,
+            Bool::False => if_false,
+        }
+    }
 }
 
 impl Not for Bool {
@@ -546,7 +986,7 @@ impl Not for Bool {
     }
 }
 
-impl std::ops::BitAnd for Bool {
+impl core::ops::BitAnd for Bool {
     type Output = Bool;
 
     fn bitand(self, other: Self) -> Self::Output {
@@ -557,7 +997,7 @@ impl std::ops::BitAnd for Bool {
     }
 }
 
-impl std::ops::BitOr for Bool {
+impl core::ops::BitOr for Bool {
     type Output = Bool;
 
     fn bitor(self, other: Self) -> Self::Output {
@@ -568,7 +1008,7 @@ impl std::ops::BitOr for Bool {
     }
 }
 
-impl std::ops::BitXor for Bool {
+impl core::ops::BitXor for Bool {
     type Output = Bool;
 
     fn bitxor(self, other: Self) -> Self::Output {
@@ -580,7 +1020,7 @@ impl std::ops::BitXor for Bool {
 }
 
 /// Represents a nibble (4 bits).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Nibble {
     bits: [Bit; 4],
 }
@@ -603,7 +1043,7 @@ impl Nibble {
 
     /// One value for a nibble (0b0001).
     pub const ONE: Nibble = Nibble {
-        bits: [Bit::Zero, Bit::Zero, Bit::Zero, Bit::One],
+        bits: [Bit::One, Bit::Zero, Bit::Zero, Bit::Zero],
     };
 }
 
@@ -617,7 +1057,7 @@ impl Not for Nibble {
     }
 }
 
-impl std::ops::BitAnd for Nibble {
+impl core::ops::BitAnd for Nibble {
     type Output = Nibble;
 
     fn bitand(self, other: Self) -> Self::Output {
@@ -629,7 +1069,7 @@ impl std::ops::BitAnd for Nibble {
     }
 }
 
-impl std::ops::BitOr for Nibble {
+impl core::ops::BitOr for Nibble {
     type Output = Nibble;
 
     fn bitor(self, other: Self) -> Self::Output {
@@ -641,7 +1081,7 @@ impl std::ops::BitOr for Nibble {
     }
 }
 
-impl std::ops::BitXor for Nibble {
+impl core::ops::BitXor for Nibble {
     type Output = Nibble;
 
     fn bitxor(self, other: Self) -> Self::Output {
@@ -653,7 +1093,7 @@ impl std::ops::BitXor for Nibble {
     }
 }
 
-impl std::ops::BitXorAssign for Nibble {
+impl core::ops::BitXorAssign for Nibble {
     fn bitxor_assign(&mut self, other: Self) {
         for i in 0..4 {
             self.bits[i] ^= other.bits[i];
@@ -661,7 +1101,7 @@ impl std::ops::BitXorAssign for Nibble {
     }
 }
 
-impl std::ops::BitAndAssign for Nibble {
+impl core::ops::BitAndAssign for Nibble {
     fn bitand_assign(&mut self, other: Self) {
         for i in 0..4 {
             self.bits[i] &= other.bits[i];
@@ -669,7 +1109,7 @@ impl std::ops::BitAndAssign for Nibble {
     }
 }
 
-impl std::ops::BitOrAssign for Nibble {
+impl core::ops::BitOrAssign for Nibble {
     fn bitor_assign(&mut self, other: Self) {
         for i in 0..4 {
             self.bits[i] |= other.bits[i];
@@ -677,6 +1117,7 @@ impl std::ops::BitOrAssign for Nibble {
     }
 }
 
+/// Formats the nibble as its four binary digits, MSB first. Use `value` for the number.
 impl Display for Nibble {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         for bit in self.bits.iter().rev() {
@@ -740,355 +1181,8379 @@ impl ShrAssign<u8> for Nibble {
     }
 }
 
-/// Represents a word (16 bits).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Word {
-    bits: [Bit; 16],
-}
-
-impl ShlAssign<u8> for Byte {
-    fn shl_assign(&mut self, shift: u8) {
-        *self = *self << shift;
+impl From<u8> for Nibble {
+    /// Keeps the low 4 bits of `value`; higher bits are discarded.
+    fn from(value: u8) -> Self {
+        let mut bits = [Bit::Zero; 4];
+        for i in 0..4 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+        }
+        Nibble { bits }
     }
 }
 
-impl ShrAssign<u8> for Byte {
-    fn shr_assign(&mut self, shift: u8) {
-        *self = *self >> shift;
+impl From<Nibble> for u8 {
+    fn from(value: Nibble) -> Self {
+        let mut result = 0u8;
+        for i in 0..4 {
+            if value.bits[i] == Bit::One {
+                result |= 1 << i;
+            }
+        }
+        result
     }
 }
 
-/// Either type for holding one of two possible values.
-pub enum Either<L, R> {
-    Left(L),
-    Right(R),
-}
+impl Add for Nibble {
+    type Output = Nibble;
 
-impl<L, R> Either<L, R> {
-    /// Maps the left value using the provided function.
-    pub fn map_left<F, T>(self, f: F) -> Either<T, R>
-    where
-        F: FnOnce(L) -> T,
-    {
-        match self {
-            Either::Left(l) => Either::Left(f(l)),
-            Either::Right(r) => Either::Right(r),
+    /// Ripple-carry addition, wrapping modulo 16.
+    fn add(self, other: Nibble) -> Nibble {
+        let mut carry = Bit::Zero;
+        let mut bits = [Bit::Zero; 4];
+        for i in 0..4 {
+            let (sum, new_carry) = full_adder(self.bits[i], other.bits[i], carry);
+            bits[i] = sum;
+            carry = new_carry;
         }
+        Nibble { bits }
     }
+}
 
-    /// Maps the right value using the provided function.
-    pub fn map_right<F, T>(self, f: F) -> Either<L, T>
-    where
-        F: FnOnce(R) -> T,
-    {
-        match self {
-            Either::Left(l) => Either::Left(l),
-            Either::Right(r) => Either::Right(f(r)),
+impl Sub for Nibble {
+    type Output = Nibble;
+
+    /// Ripple-borrow subtraction, wrapping modulo 16.
+    fn sub(self, other: Nibble) -> Nibble {
+        let mut borrow = Bit::Zero;
+        let mut bits = [Bit::Zero; 4];
+        for i in 0..4 {
+            let (diff, new_borrow) = full_subtractor(self.bits[i], other.bits[i], borrow);
+            bits[i] = diff;
+            borrow = new_borrow;
         }
+        Nibble { bits }
     }
 }
 
-impl Mul for Bit {
+impl Index<usize> for Nibble {
     type Output = Bit;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.and(&rhs)
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
     }
 }
 
-// -------------------- N8 --------------------
-
-/// Unsigned 8-bit integer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct N8 {
-    bits: [Bit; 8],
+impl IndexMut<usize> for Nibble {
+    fn index_mut(&mut self, index: usize) -> &mut Bit {
+        &mut self.bits[index]
+    }
 }
 
-impl N8 {
-    /// Creates a new `N8` from an array of 8 bits.
-    pub fn new(bits: [Bit; 8]) -> Self {
-        N8 { bits }
+impl Nibble {
+    /// Returns an iterator over the bits, least significant bit first.
+    pub fn iter_bits(&self) -> core::array::IntoIter<Bit, 4> {
+        self.bits.into_iter()
     }
+}
 
-    /// Maximum value for N8 (0xFF).
-    pub const MAX: N8 = N8 {
-        bits: [
-            Bit::One,
-            Bit::One,
-            Bit::One,
-            Bit::One,
-            Bit::One,
-            Bit::One,
-            Bit::One,
-            Bit::One,
-        ],
-    };
+impl IntoIterator for Nibble {
+    type Item = Bit;
+    type IntoIter = core::array::IntoIter<Bit, 4>;
 
-    /// Minimum value for N8 (0).
-    pub const MIN: N8 = Self::ZERO;
+    /// Yields the bits least significant first, matching `FromIterator`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits.into_iter()
+    }
+}
 
-    /// Zero value for N8.
+impl Nibble {
+    /// Returns the numeric value of the nibble (0–15).
+    pub fn value(&self) -> u8 {
+        u8::from(*self)
+    }
+
+    /// Returns `Bool::True` if the nibble is a valid BCD digit (0–9).
+    pub fn is_valid_bcd(&self) -> Bool {
+        // Invalid digits 10–15 are exactly those with bit 3 set plus bit 2 or bit 1.
+        (self.bits[3] & (self.bits[2] | self.bits[1])).is_zero()
+    }
+}
+
+impl Nibble {
+    /// Rotates bits to the left by `n` positions.
+    pub fn rotate_left(&self, n: usize) -> Nibble {
+        let mut result = [Bit::Zero; 4];
+        let n = n % 4;
+        for i in 0..4 {
+            result[(i + n) % 4] = self.bits[i];
+        }
+        Nibble { bits: result }
+    }
+
+    /// Rotates bits to the right by `n` positions.
+    pub fn rotate_right(&self, n: usize) -> Nibble {
+        let mut result = [Bit::Zero; 4];
+        let n = n % 4;
+        for i in 0..4 {
+            result[(i + 4 - n) % 4] = self.bits[i];
+        }
+        Nibble { bits: result }
+    }
+}
+
+impl BitwiseRotate for Nibble {
+    fn rotate_left(&mut self, n: u32) {
+        *self = Nibble::rotate_left(self, n as usize);
+    }
+    fn rotate_right(&mut self, n: u32) {
+        *self = Nibble::rotate_right(self, n as usize);
+    }
+}
+
+impl BitwiseReverse for Nibble {
+    fn reverse_bits(&mut self) {
+        self.bits.reverse();
+    }
+}
+
+impl BitCount for Nibble {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        4 - self.count_ones()
+    }
+}
+
+/// Represents a word (16 bits).
+///
+/// Like `Byte`, a `Word` is a raw bit container: it offers bit access, bitwise
+/// logic and shifts but no arithmetic. Use `N16` for 16-bit numeric values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Word {
+    bits: [Bit; 16],
+}
+
+impl Word {
+    /// Creates a new `Word` from an array of 16 bits.
+    pub fn new(bits: [Bit; 16]) -> Self {
+        Word { bits }
+    }
+
+    /// Gets the bit at the specified index.
+    pub fn get_bit(&self, index: usize) -> Bit {
+        self.bits[index]
+    }
+
+    /// Sets the bit at the specified index.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        self.bits[index] = bit;
+    }
+
+    /// Returns a reference to the underlying bits.
+    pub fn get_bits(&self) -> &[Bit; 16] {
+        &self.bits
+    }
+
+    /// Inverts all bits in the word.
+    pub fn invert(&mut self) {
+        for bit in &mut self.bits {
+            *bit = bit.not()
+        }
+    }
+}
+
+impl Display for Word {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for bit in self.bits.iter().rev() {
+            write!(f, "{}", bit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Always prints all sixteen digits, MSB first; width and `#` flags still apply.
+impl fmt::Binary for Word {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = u16::from(*self);
+        let mut digits = [b'0'; 16];
+        for i in 0..16 {
+            if value & (1 << (15 - i)) != 0 {
+                digits[i] = b'1';
+            }
+        }
+        f.pad_integral(true, "0b", core::str::from_utf8(&digits).unwrap())
+    }
+}
+
+impl From<u16> for Word {
+    fn from(value: u16) -> Self {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+        }
+        Word { bits }
+    }
+}
+
+impl From<Word> for u16 {
+    fn from(value: Word) -> Self {
+        let mut result = 0u16;
+        for i in 0..16 {
+            if value.bits[i] == Bit::One {
+                result |= 1 << i;
+            }
+        }
+        result
+    }
+}
+
+impl Not for Word {
+    type Output = Word;
+
+    fn not(self) -> Self::Output {
+        Word {
+            bits: self.bits.map(|bit| !bit),
+        }
+    }
+}
+
+impl core::ops::BitAnd for Word {
+    type Output = Word;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
+            bits[i] = self.bits[i] & other.bits[i];
+        }
+        Word { bits }
+    }
+}
+
+impl core::ops::BitOr for Word {
+    type Output = Word;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
+            bits[i] = self.bits[i] | other.bits[i];
+        }
+        Word { bits }
+    }
+}
+
+impl core::ops::BitXor for Word {
+    type Output = Word;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
+            bits[i] = self.bits[i] ^ other.bits[i];
+        }
+        Word { bits }
+    }
+}
+
+impl Shl<u8> for Word {
+    type Output = Word;
+
+    fn shl(self, shift: u8) -> Self::Output {
+        let mut result = Word::new([Bit::Zero; 16]);
+        let shift = shift as usize;
+        if shift >= 16 {
+            return result;
+        }
+        for i in shift..16 {
+            result.bits[i] = self.bits[i - shift];
+        }
+        result
+    }
+}
+
+impl Shr<u8> for Word {
+    type Output = Word;
+
+    fn shr(self, shift: u8) -> Self::Output {
+        let mut result = Word::new([Bit::Zero; 16]);
+        let shift = shift as usize;
+        if shift >= 16 {
+            return result;
+        }
+        for i in 0..(16 - shift) {
+            result.bits[i] = self.bits[i + shift];
+        }
+        result
+    }
+}
+
+impl ShlAssign<u8> for Word {
+    fn shl_assign(&mut self, shift: u8) {
+        *self = *self << shift;
+    }
+}
+
+impl ShrAssign<u8> for Word {
+    fn shr_assign(&mut self, shift: u8) {
+        *self = *self >> shift;
+    }
+}
+
+impl ShlAssign<u8> for Byte {
+    fn shl_assign(&mut self, shift: u8) {
+        *self = *self << shift;
+    }
+}
+
+impl ShrAssign<u8> for Byte {
+    fn shr_assign(&mut self, shift: u8) {
+        *self = *self >> shift;
+    }
+}
+
+/// Either type for holding one of two possible values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Maps the left value using the provided function.
+    pub fn map_left<F, T>(self, f: F) -> Either<T, R>
+    where
+        F: FnOnce(L) -> T,
+    {
+        match self {
+            Either::Left(l) => Either::Left(f(l)),
+            Either::Right(r) => Either::Right(r),
+        }
+    }
+
+    /// Maps the right value using the provided function.
+    pub fn map_right<F, T>(self, f: F) -> Either<L, T>
+    where
+        F: FnOnce(R) -> T,
+    {
+        match self {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(r) => Either::Right(f(r)),
+        }
+    }
+
+    /// Returns `true` if this is a `Left` value.
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    /// Returns `true` if this is a `Right` value.
+    pub fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    /// Returns the left value, or `None` if this is `Right`.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(l) => Some(l),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, or `None` if this is `Left`.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(r) => Some(r),
+        }
+    }
+
+    /// Returns the left value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is a `Right` value.
+    pub fn unwrap_left(self) -> L {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(_) => panic!("called `Either::unwrap_left()` on a `Right` value"),
+        }
+    }
+
+    /// Returns the right value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is a `Left` value.
+    pub fn unwrap_right(self) -> R {
+        match self {
+            Either::Left(_) => panic!("called `Either::unwrap_right()` on a `Left` value"),
+            Either::Right(r) => r,
+        }
+    }
+
+    /// Swaps the variants, turning `Left` into `Right` and vice versa.
+    pub fn swap(self) -> Either<R, L> {
+        match self {
+            Either::Left(l) => Either::Right(l),
+            Either::Right(r) => Either::Left(r),
+        }
+    }
+
+    /// Borrows the contained value, converting `&Either<L, R>` to `Either<&L, &R>`.
+    pub fn as_ref(&self) -> Either<&L, &R> {
+        match self {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(r) => Either::Right(r),
+        }
+    }
+
+    /// Folds both variants to a common type, applying `f` to a left value or `g` to a right value.
+    pub fn either<F, G, U>(self, f: F, g: G) -> U
+    where
+        F: FnOnce(L) -> U,
+        G: FnOnce(R) -> U,
+    {
+        match self {
+            Either::Left(l) => f(l),
+            Either::Right(r) => g(r),
+        }
+    }
+}
+
+impl<L: Display, R: Display> Display for Either<L, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Either::Left(l) => write!(f, "Left({})", l),
+            Either::Right(r) => write!(f, "Right({})", r),
+        }
+    }
+}
+
+impl<T> Either<T, T> {
+    /// Returns the contained value, whichever variant holds it.
+    pub fn into_inner(self) -> T {
+        match self {
+            Either::Left(value) | Either::Right(value) => value,
+        }
+    }
+}
+
+impl Mul for Bit {
+    type Output = Bit;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.and(&rhs)
+    }
+}
+
+impl Index<usize> for Word {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl IndexMut<usize> for Word {
+    fn index_mut(&mut self, index: usize) -> &mut Bit {
+        &mut self.bits[index]
+    }
+}
+
+// -------------------- Bits --------------------
+
+#[cfg(feature = "alloc")]
+pub use dynamic::Bits;
+
+#[cfg(feature = "alloc")]
+mod dynamic {
+    use super::*;
+
+    /// Dynamic-length bit string, stored least significant bit first.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct Bits {
+        bits: Vec<Bit>,
+    }
+
+    impl Bits {
+        /// Creates an empty `Bits`.
+        pub fn new() -> Self {
+            Bits { bits: Vec::new() }
+        }
+
+        /// Appends a bit above the current most significant bit.
+        pub fn push(&mut self, bit: Bit) {
+            self.bits.push(bit);
+        }
+
+        /// Removes and returns the most significant bit, or `None` if empty.
+        pub fn pop(&mut self) -> Option<Bit> {
+            self.bits.pop()
+        }
+
+        /// Returns the bit at `index`, or `None` if out of range.
+        pub fn get(&self, index: usize) -> Option<Bit> {
+            self.bits.get(index).copied()
+        }
+
+        /// Sets the bit at `index`. Panics if `index` is out of range.
+        pub fn set(&mut self, index: usize, bit: Bit) {
+            self.bits[index] = bit;
+        }
+
+        /// Returns the number of bits.
+        pub fn len(&self) -> usize {
+            self.bits.len()
+        }
+
+        /// Returns `true` if there are no bits.
+        pub fn is_empty(&self) -> bool {
+            self.bits.is_empty()
+        }
+
+        /// Returns an iterator over the bits, least significant bit first.
+        pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, Bit>> {
+            self.bits.iter().copied()
+        }
+
+        /// Returns the bits as a slice, least significant bit first.
+        pub fn as_slice(&self) -> &[Bit] {
+            &self.bits
+        }
+
+        /// Combines two bit strings bit by bit. The shorter operand is
+        /// zero-extended, so the result is as long as the longer one.
+        fn zip_with(&self, other: &Bits, op: fn(Bit, Bit) -> Bit) -> Bits {
+            let len = self.len().max(other.len());
+            (0..len)
+                .map(|i| {
+                    op(
+                        self.get(i).unwrap_or(Bit::Zero),
+                        other.get(i).unwrap_or(Bit::Zero),
+                    )
+                })
+                .collect()
+        }
+
+        /// Copies the low `N` bits into an array, failing if a higher bit is set.
+        fn to_array<const N: usize>(&self) -> Result<[Bit; N], ConversionError> {
+            if self.bits.iter().skip(N).any(|&bit| bit == Bit::One) {
+                return Err(ConversionError);
+            }
+            let mut bits = [Bit::Zero; N];
+            for (i, bit) in self.bits.iter().take(N).enumerate() {
+                bits[i] = *bit;
+            }
+            Ok(bits)
+        }
+    }
+
+    impl FromIterator<Bit> for Bits {
+        fn from_iter<I: IntoIterator<Item = Bit>>(iter: I) -> Self {
+            Bits {
+                bits: iter.into_iter().collect(),
+            }
+        }
+    }
+
+    impl IntoIterator for Bits {
+        type Item = Bit;
+        type IntoIter = vec::IntoIter<Bit>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.bits.into_iter()
+        }
+    }
+
+    impl FromStr for Bits {
+        type Err = ParseBitsError;
+
+        /// Parses a string of `0`/`1` digits written most significant bit first.
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let mut bits = Vec::with_capacity(s.len());
+            for (index, ch) in s.chars().enumerate() {
+                bits.push(match ch {
+                    '0' => Bit::Zero,
+                    '1' => Bit::One,
+                    _ => return Err(ParseBitsError::InvalidChar { ch, index }),
+                });
+            }
+            bits.reverse();
+            Ok(Bits { bits })
+        }
+    }
+
+    impl Display for Bits {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            for bit in self.bits.iter().rev() {
+                write!(f, "{}", bit)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl core::ops::BitAnd for &Bits {
+        type Output = Bits;
+
+        fn bitand(self, other: &Bits) -> Bits {
+            self.zip_with(other, |a, b| a & b)
+        }
+    }
+
+    impl core::ops::BitOr for &Bits {
+        type Output = Bits;
+
+        fn bitor(self, other: &Bits) -> Bits {
+            self.zip_with(other, |a, b| a | b)
+        }
+    }
+
+    impl core::ops::BitXor for &Bits {
+        type Output = Bits;
+
+        fn bitxor(self, other: &Bits) -> Bits {
+            self.zip_with(other, |a, b| a ^ b)
+        }
+    }
+
+    impl core::ops::BitAnd for Bits {
+        type Output = Bits;
+
+        fn bitand(self, other: Bits) -> Bits {
+            &self & &other
+        }
+    }
+
+    impl core::ops::BitOr for Bits {
+        type Output = Bits;
+
+        fn bitor(self, other: Bits) -> Bits {
+            &self | &other
+        }
+    }
+
+    impl core::ops::BitXor for Bits {
+        type Output = Bits;
+
+        fn bitxor(self, other: Bits) -> Bits {
+            &self ^ &other
+        }
+    }
+
+    impl From<Byte> for Bits {
+        fn from(value: Byte) -> Self {
+            Bits {
+                bits: value.bits.to_vec(),
+            }
+        }
+    }
+
+    impl From<N8> for Bits {
+        fn from(value: N8) -> Self {
+            Bits {
+                bits: value.bits.to_vec(),
+            }
+        }
+    }
+
+    impl From<N16> for Bits {
+        fn from(value: N16) -> Self {
+            Bits {
+                bits: value.bits.to_vec(),
+            }
+        }
+    }
+
+    impl TryFrom<Bits> for Byte {
+        type Error = ConversionError;
+
+        /// Fails if any bit at index 8 or above is set; shorter inputs are zero-extended.
+        fn try_from(value: Bits) -> Result<Self, Self::Error> {
+            Ok(Byte {
+                bits: value.to_array()?,
+            })
+        }
+    }
+
+    impl TryFrom<Bits> for N8 {
+        type Error = ConversionError;
+
+        /// Fails if any bit at index 8 or above is set; shorter inputs are zero-extended.
+        fn try_from(value: Bits) -> Result<Self, Self::Error> {
+            Ok(N8 {
+                bits: value.to_array()?,
+            })
+        }
+    }
+
+    impl TryFrom<Bits> for N16 {
+        type Error = ConversionError;
+
+        /// Fails if any bit at index 16 or above is set; shorter inputs are zero-extended.
+        fn try_from(value: Bits) -> Result<Self, Self::Error> {
+            Ok(N16 {
+                bits: value.to_array()?,
+            })
+        }
+    }
+}
+
+// -------------------- N8 --------------------
+
+/// Unsigned 8-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct N8 {
+    bits: [Bit; 8],
+}
+
+impl N8 {
+    /// Creates a new `N8` from an array of 8 bits.
+    pub fn new(bits: [Bit; 8]) -> Self {
+        N8 { bits }
+    }
+
+    /// Maximum value for N8 (0xFF).
+    pub const MAX: N8 = N8 {
+        bits: [
+            Bit::One,
+            Bit::One,
+            Bit::One,
+            Bit::One,
+            Bit::One,
+            Bit::One,
+            Bit::One,
+            Bit::One,
+        ],
+    };
+
+    /// Minimum value for N8 (0).
+    pub const MIN: N8 = Self::ZERO;
+
+    /// Zero value for N8.
     pub const ZERO: N8 = N8 {
         bits: [Bit::Zero; 8],
     };
 
-    /// One value for N8.
-    pub const ONE: N8 = N8::from_u8(1);
-    pub const TWO: N8 = N8::from_u8(2);
-    pub const THREE: N8 = N8::from_u8(3);
-    pub const FOUR: N8 = N8::from_u8(4);
-    pub const FIVE: N8 = N8::from_u8(5);
-    pub const SIX: N8 = N8::from_u8(6);
-    pub const SEVEN: N8 = N8::from_u8(7);
-    pub const EIGHT: N8 = N8::from_u8(8);
-    pub const NINE: N8 = N8::from_u8(9);
-    pub const TEN: N8 = N8::from_u8(10);
+    /// One value for N8.
+    pub const ONE: N8 = N8::from_u8(1);
+    pub const TWO: N8 = N8::from_u8(2);
+    pub const THREE: N8 = N8::from_u8(3);
+    pub const FOUR: N8 = N8::from_u8(4);
+    pub const FIVE: N8 = N8::from_u8(5);
+    pub const SIX: N8 = N8::from_u8(6);
+    pub const SEVEN: N8 = N8::from_u8(7);
+    pub const EIGHT: N8 = N8::from_u8(8);
+    pub const NINE: N8 = N8::from_u8(9);
+    pub const TEN: N8 = N8::from_u8(10);
+}
+
+/// Shifts along the bit array like `Byte`; shifting by 8 or more yields zero.
+impl Shl<u8> for N8 {
+    type Output = N8;
+
+    fn shl(self, shift: u8) -> Self::Output {
+        let mut result = N8::new([Bit::Zero; 8]);
+        let shift = shift as usize;
+        if shift >= 8 {
+            return result;
+        }
+        for i in shift..8 {
+            result.bits[i] = self.bits[i - shift];
+        }
+        result
+    }
+}
+
+/// Shifts along the bit array like `Byte`; shifting by 8 or more yields zero.
+impl Shr<u8> for N8 {
+    type Output = N8;
+
+    fn shr(self, shift: u8) -> Self::Output {
+        let mut result = N8::new([Bit::Zero; 8]);
+        let shift = shift as usize;
+        if shift >= 8 {
+            return result;
+        }
+        for i in 0..(8 - shift) {
+            result.bits[i] = self.bits[i + shift];
+        }
+        result
+    }
+}
+
+impl ShlAssign<u8> for N8 {
+    fn shl_assign(&mut self, shift: u8) {
+        *self = *self << shift;
+    }
+}
+
+impl ShrAssign<u8> for N8 {
+    fn shr_assign(&mut self, shift: u8) {
+        *self = *self >> shift;
+    }
+}
+
+/// Full adder for single bits.
+fn full_adder(a: Bit, b: Bit, carry: Bit) -> (Bit, Bit) {
+    let sum = a ^ b ^ carry;
+    let new_carry = (a & b) | (a & carry) | (b & carry);
+    (sum, new_carry)
+}
+
+/// Ripple-carry adder over LSB-first bit slices of any width.
+///
+/// The shorter operand is treated as zero-extended. Returns the sum, as wide as
+/// the longer operand, and the carry out of the top bit.
+#[cfg(feature = "alloc")]
+pub fn ripple_add(a: &[Bit], b: &[Bit]) -> (Vec<Bit>, Bit) {
+    let width = a.len().max(b.len());
+    let mut carry = Bit::Zero;
+    let mut sum = Vec::with_capacity(width);
+    for i in 0..width {
+        let x = a.get(i).copied().unwrap_or(Bit::Zero);
+        let y = b.get(i).copied().unwrap_or(Bit::Zero);
+        let (bit, new_carry) = full_adder(x, y, carry);
+        sum.push(bit);
+        carry = new_carry;
+    }
+    (sum, carry)
+}
+
+impl Add for N8 {
+    type Output = N8;
+
+    fn add(self, other: N8) -> N8 {
+        self.overflowing_add(other).0
+    }
+}
+
+impl AddAssign for N8 {
+    fn add_assign(&mut self, other: N8) {
+        *self = *self + other;
+    }
+}
+
+impl From<u8> for N8 {
+    fn from(value: u8) -> Self {
+        let mut bits = [Bit::Zero; 8];
+        for i in 0..8 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+        }
+        N8 { bits }
+    }
+}
+
+impl From<N8> for u8 {
+    fn from(value: N8) -> Self {
+        let mut result = 0u8;
+        for i in 0..8 {
+            if value.bits[i] == Bit::One {
+                result |= 1 << i;
+            }
+        }
+        result
+    }
+}
+
+impl N8 {
+    /// Creates an `N8` from a primitive `u8` (const).
+    pub const fn from_u8(value: u8) -> Self {
+        let mut bits = [Bit::Zero; 8];
+        let mut i = 0;
+        while i < 8 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+            i += 1;
+        }
+        N8 { bits }
+    }
+
+    /// Bitwise AND usable in const contexts.
+    pub const fn bitand_const(self, other: N8) -> N8 {
+        let mut bits = [Bit::Zero; 8];
+        let mut i = 0;
+        while i < 8 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::One, Bit::One) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N8 { bits }
+    }
+
+    /// Bitwise OR usable in const contexts.
+    pub const fn bitor_const(self, other: N8) -> N8 {
+        let mut bits = [Bit::Zero; 8];
+        let mut i = 0;
+        while i < 8 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::Zero) => Bit::Zero,
+                _ => Bit::One,
+            };
+            i += 1;
+        }
+        N8 { bits }
+    }
+
+    /// Bitwise XOR usable in const contexts.
+    pub const fn bitxor_const(self, other: N8) -> N8 {
+        let mut bits = [Bit::Zero; 8];
+        let mut i = 0;
+        while i < 8 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::One) | (Bit::One, Bit::Zero) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N8 { bits }
+    }
+
+    /// Bitwise NOT usable in const contexts.
+    pub const fn not_const(self) -> N8 {
+        let mut bits = [Bit::Zero; 8];
+        let mut i = 0;
+        while i < 8 {
+            bits[i] = match self.bits[i] {
+                Bit::Zero => Bit::One,
+                Bit::One => Bit::Zero,
+            };
+            i += 1;
+        }
+        N8 { bits }
+    }
+
+    /// Creates a new `N8` from an array of 8 bits.
+    pub fn new_from_bits(bits: [Bit; 8]) -> Self {
+        N8 { bits }
+    }
+
+    /// Returns the value as a `Byte`.
+    pub fn as_byte(&self) -> Byte {
+        Byte { bits: self.bits }
+    }
+}
+
+impl BitCount for N8 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&&b| b == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        self.bits.iter().filter(|&&b| b == Bit::Zero).count() as u32
+    }
+}
+
+impl BitwiseReverse for N8 {
+    fn reverse_bits(&mut self) {
+        self.bits.reverse();
+    }
+}
+
+impl BitwiseRotate for N8 {
+    fn rotate_left(&mut self, n: u32) {
+        let val = u8::from(*self);
+        let rotated = val.rotate_left(n);
+        *self = rotated.into();
+    }
+    fn rotate_right(&mut self, n: u32) {
+        let val = u8::from(*self);
+        let rotated = val.rotate_right(n);
+        *self = rotated.into();
+    }
+}
+
+/// Parses an MSB-first binary digit string, e.g. `"101"` is 5.
+impl FromStr for N8 {
+    type Err = ParseBitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(N8 {
+            bits: parse_bits(s)?,
+        })
+    }
+}
+
+/// Full subtractor for single bits.
+fn full_subtractor(a: Bit, b: Bit, borrow: Bit) -> (Bit, Bit) {
+    let diff = a ^ b ^ borrow;
+    let new_borrow = (b & !a) | (borrow & !(a ^ b));
+    (diff, new_borrow)
+}
+
+impl Sub for N8 {
+    type Output = N8;
+
+    fn sub(self, other: N8) -> N8 {
+        self.overflowing_sub(other).0
+    }
+}
+
+impl PartialOrd for N8 {
+    fn partial_cmp(&self, other: &N8) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for N8 {
+    fn cmp(&self, other: &N8) -> Ordering {
+        u8::from(*self).cmp(&u8::from(*other))
+    }
+}
+
+impl SubAssign for N8 {
+    fn sub_assign(&mut self, other: N8) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for N8 {
+    type Output = N8;
+
+    /// Shift-and-add multiplication, wrapping modulo 256 like `Add`.
+    fn mul(self, other: N8) -> N8 {
+        let mut product = N8::ZERO;
+        for shift in 0..8 {
+            if other.bits[shift] == Bit::One {
+                let mut partial = [Bit::Zero; 8];
+                partial[shift..].copy_from_slice(&self.bits[..8 - shift]);
+                product += N8 { bits: partial };
+            }
+        }
+        product
+    }
+}
+
+impl N8 {
+    /// Restoring division over the bit arrays, returning `(quotient, remainder)`.
+    ///
+    /// The remainder register is 9 bits wide so that shifting in the next
+    /// dividend bit never loses the top bit before the trial subtraction.
+    fn div_rem(self, divisor: N8) -> (N8, N8) {
+        if divisor == N8::ZERO {
+            panic!("attempt to divide by zero");
+        }
+        let mut quotient = [Bit::Zero; 8];
+        let mut remainder = [Bit::Zero; 9];
+        for i in (0..8).rev() {
+            remainder.copy_within(0..8, 1);
+            remainder[0] = self.bits[i];
+
+            let mut borrow = Bit::Zero;
+            let mut trial = [Bit::Zero; 9];
+            for j in 0..9 {
+                let d = if j < 8 { divisor.bits[j] } else { Bit::Zero };
+                let (diff, new_borrow) = full_subtractor(remainder[j], d, borrow);
+                trial[j] = diff;
+                borrow = new_borrow;
+            }
+            if borrow == Bit::Zero {
+                remainder = trial;
+                quotient[i] = Bit::One;
+            }
+        }
+        let mut rem_bits = [Bit::Zero; 8];
+        rem_bits.copy_from_slice(&remainder[..8]);
+        (N8 { bits: quotient }, N8 { bits: rem_bits })
+    }
+}
+
+impl Div for N8 {
+    type Output = N8;
+
+    fn div(self, other: N8) -> N8 {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for N8 {
+    type Output = N8;
+
+    fn rem(self, other: N8) -> N8 {
+        self.div_rem(other).1
+    }
+}
+
+impl RemAssign for N8 {
+    fn rem_assign(&mut self, other: N8) {
+        *self = *self % other;
+    }
+}
+
+impl Display for N8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+/// Shows the decimal value followed by the bits, MSB first: `N8(170 = 0b10101010)`.
+impl fmt::Debug for N8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = u8::from(*self);
+        write!(f, "N8({} = {:#010b})", value, value)
+    }
+}
+
+impl N8 {
+    /// Ripple-carry addition returning the wrapped sum and the final carry out.
+    pub fn overflowing_add(self, other: N8) -> (N8, Bool) {
+        let mut carry = Bit::Zero;
+        let mut result_bits = [Bit::Zero; 8];
+        for i in 0..8 {
+            let (sum, new_carry) = full_adder(self.bits[i], other.bits[i], carry);
+            result_bits[i] = sum;
+            carry = new_carry;
+        }
+        (N8 { bits: result_bits }, carry.as_bool())
+    }
+
+    /// Addition as an `Either`: `Left(sum)` when there is no carry out, otherwise
+    /// `Right((wrapped_sum, carry))`.
+    pub fn add_either(self, other: N8) -> Either<N8, (N8, Bit)> {
+        match self.overflowing_add(other) {
+            (result, Bool::False) => Either::Left(result),
+            (result, Bool::True) => Either::Right((result, Bit::One)),
+        }
+    }
+
+    /// Checked addition. Returns `None` if the final carry out of the adder is set.
+    pub fn checked_add(self, other: N8) -> Option<N8> {
+        match self.overflowing_add(other) {
+            (result, Bool::False) => Some(result),
+            (_, Bool::True) => None,
+        }
+    }
+
+    /// Ripple-borrow subtraction returning the wrapped difference and the final borrow out.
+    pub fn overflowing_sub(self, other: N8) -> (N8, Bool) {
+        let mut borrow = Bit::Zero;
+        let mut result_bits = [Bit::Zero; 8];
+        for i in 0..8 {
+            let (diff, new_borrow) = full_subtractor(self.bits[i], other.bits[i], borrow);
+            result_bits[i] = diff;
+            borrow = new_borrow;
+        }
+        (N8 { bits: result_bits }, borrow.as_bool())
+    }
+
+    /// Checked subtraction. Returns `None` if the final borrow is set.
+    pub fn checked_sub(self, other: N8) -> Option<N8> {
+        match self.overflowing_sub(other) {
+            (result, Bool::False) => Some(result),
+            (_, Bool::True) => None,
+        }
+    }
+
+    /// Checked multiplication. Returns `None` if the product does not fit in 8 bits.
+    pub fn checked_mul(self, other: N8) -> Option<N8> {
+        u8::from(self).checked_mul(u8::from(other)).map(N8::from)
+    }
+}
+
+impl N8 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: N8) -> N8 {
+        u8::from(self).saturating_add(u8::from(other)).into()
+    }
+
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: N8) -> N8 {
+        u8::from(self).saturating_sub(u8::from(other)).into()
+    }
+}
+
+impl fmt::LowerHex for N8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&u8::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for N8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&u8::from(*self), f)
+    }
+}
+
+impl fmt::Binary for N8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&u8::from(*self), f)
+    }
+}
+
+impl fmt::Octal for N8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&u8::from(*self), f)
+    }
+}
+
+impl TryFrom<N16> for N8 {
+    type Error = ConversionError;
+
+    fn try_from(value: N16) -> Result<Self, Self::Error> {
+        if value.bits[8..].contains(&Bit::One) {
+            return Err(ConversionError);
+        }
+        let mut bits = [Bit::Zero; 8];
+        bits.copy_from_slice(&value.bits[..8]);
+        Ok(N8 { bits })
+    }
+}
+
+impl From<Byte> for N8 {
+    fn from(value: Byte) -> Self {
+        N8 { bits: value.bits }
+    }
+}
+
+impl From<N8> for Byte {
+    fn from(value: N8) -> Self {
+        value.as_byte()
+    }
+}
+
+impl BitScan for N8 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
+    }
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl N8 {
+    /// Returns `Bool::True` if exactly one bit is set. Zero is not a power of two.
+    pub fn is_power_of_two(&self) -> Bool {
+        Bool::new(self.count_ones() == 1)
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// Zero maps to one. If the result does not fit, it wraps to zero, which is
+    /// what the primitive `next_power_of_two` does in release builds.
+    pub fn next_power_of_two(self) -> N8 {
+        u8::from(self)
+            .checked_next_power_of_two()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+impl N8 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> N8 {
+        let mut result = N8::ONE;
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl MulAssign for N8 {
+    fn mul_assign(&mut self, other: N8) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for N8 {
+    fn div_assign(&mut self, other: N8) {
+        *self = *self / other;
+    }
+}
+
+impl Index<usize> for N8 {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl N8 {
+    /// Returns an iterator over the bits, least significant bit first.
+    pub fn iter_bits(&self) -> core::array::IntoIter<Bit, 8> {
+        self.bits.into_iter()
+    }
+
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = u8::from(*self);
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
+    }
+}
+
+impl IntoIterator for N8 {
+    type Item = Bit;
+    type IntoIter = core::array::IntoIter<Bit, 8>;
+
+    /// Yields the bits least significant first, matching `FromIterator`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits.into_iter()
+    }
+}
+
+impl FromIterator<Bit> for N8 {
+    fn from_iter<I: IntoIterator<Item = Bit>>(iter: I) -> Self {
+        let mut bits = [Bit::Zero; 8];
+        for (i, bit) in iter.into_iter().enumerate().take(8) {
+            bits[i] = bit;
+        }
+        N8 { bits }
+    }
+}
+
+/// Interprets the array least significant bit first: element 0 becomes bit 0.
+impl From<[bool; 8]> for N8 {
+    fn from(value: [bool; 8]) -> Self {
+        let mut bits = [Bit::Zero; 8];
+        for (i, &b) in value.iter().enumerate() {
+            if b {
+                bits[i] = Bit::One;
+            }
+        }
+        N8 { bits }
+    }
+}
+
+impl N8 {
+    /// Shifts left by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 8 (`u8::wrapping_shl`), so
+    /// a shift by 8 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_left_carry(self, n: u32) -> (N8, Bit) {
+        let n = (n % 8) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 8];
+        bits[n..].copy_from_slice(&self.bits[..8 - n]);
+        (N8 { bits }, self.bits[8 - n])
+    }
+
+    /// Logical right shift by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 8 (`u8::wrapping_shr`), so
+    /// a shift by 8 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_right_carry(self, n: u32) -> (N8, Bit) {
+        let n = (n % 8) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 8];
+        bits[..8 - n].copy_from_slice(&self.bits[n..]);
+        (N8 { bits }, self.bits[n - 1])
+    }
+}
+
+impl N8 {
+    /// Full 16-bit product, by shift-and-add into a 16-bit accumulator.
+    pub fn widening_mul(self, rhs: N8) -> N16 {
+        let mut product = [Bit::Zero; 16];
+        for shift in 0..8 {
+            if rhs.bits[shift] == Bit::One {
+                let mut carry = Bit::Zero;
+                for i in 0..8 {
+                    let (sum, new_carry) = full_adder(product[shift + i], self.bits[i], carry);
+                    product[shift + i] = sum;
+                    carry = new_carry;
+                }
+                product[shift + 8] = carry;
+            }
+        }
+        N16 { bits: product }
+    }
+}
+
+impl KarccInt for N8 {
+    type Primitive = u8;
+
+    const ZERO: Self = N8::ZERO;
+    const ONE: Self = N8::ONE;
+    const MIN: Self = N8::MIN;
+    const MAX: Self = N8::MAX;
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        N8::checked_add(self, rhs)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        N8::checked_sub(self, rhs)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        N8::checked_mul(self, rhs)
+    }
+}
+
+impl N8 {
+    /// Encodes the value as reflected binary Gray code, `x ^ (x >> 1)`.
+    pub fn to_gray(self) -> N8 {
+        self ^ (self >> 1)
+    }
+
+    /// Decodes a Gray code value, the inverse of `to_gray`.
+    pub fn from_gray(self) -> N8 {
+        let mut value = self;
+        let mut shifted = self >> 1;
+        while shifted != N8::ZERO {
+            value = value ^ shifted;
+            shifted >>= 1;
+        }
+        value
+    }
+}
+
+impl Parity for N8 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
+
+impl core::ops::BitXor for N8 {
+    type Output = N8;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 8];
+        for i in 0..8 {
+            bits[i] = self.bits[i] ^ other.bits[i];
+        }
+        N8 { bits }
+    }
+}
+
+impl N8 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &N8) -> u32 {
+        (*self ^ *other).count_ones()
+    }
+}
+
+impl N8 {
+    /// Two's-complement negation, `!self + 1` wrapping modulo 2^8.
+    pub fn wrapping_neg(self) -> N8 {
+        let mut bits = [Bit::Zero; 8];
+        let mut carry = Bit::One;
+        for i in 0..8 {
+            let (sum, new_carry) = Bit::half_adder(!self.bits[i], carry);
+            bits[i] = sum;
+            carry = new_carry;
+        }
+        N8 { bits }
+    }
+}
+
+impl N8 {
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: N8) -> Option<N8> {
+        u8::from(self).checked_div(u8::from(rhs)).map(N8::from)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: N8) -> Option<N8> {
+        u8::from(self).checked_rem(u8::from(rhs)).map(N8::from)
+    }
+}
+
+impl N8 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(8 + 8 / group.max(1));
+        for i in (0..8).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
+            }
+        }
+        text
+    }
+}
+
+impl N8 {
+    /// Parses a hexadecimal string such as `"0xFF"` or `"ff"`.
+    pub fn from_hex_str(s: &str) -> Result<N8, ParseBitsError> {
+        Ok(N8 {
+            bits: parse_hex_bits(s)?,
+        })
+    }
+}
+
+impl N8 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 8.
+    pub fn bit_range(&self, start: usize, len: usize) -> N8 {
+        assert!(
+            start + len <= 8,
+            "bit range {}..{} out of range for 8 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 8];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        N8 { bits }
+    }
+}
+
+impl N8 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 8 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> N8 {
+        assert!(index < 8, "bit index {} out of range for 8 bits", index);
+        self.bits[index] = bit;
+        self
+    }
+
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 8 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 8, "bit index {} out of range for 8 bits", index);
+        self.bits[index] = bit;
+    }
+
+    /// Clears the bit at `index`. Panics if `index` is 8 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 8, "bit index {} out of range for 8 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
+
+    /// Flips the bit at `index`. Panics if `index` is 8 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 8, "bit index {} out of range for 8 bits", index);
+        self.bits[index] = !self.bits[index];
+    }
+}
+
+impl N8 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: N8) -> N8 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of two values.
+    pub fn max(self, other: N8) -> N8 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: N8, hi: N8) -> N8 {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+impl Sum for N8 {
+    fn sum<I: Iterator<Item = N8>>(iter: I) -> N8 {
+        iter.fold(<N8 as KarccInt>::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a N8> for N8 {
+    fn sum<I: Iterator<Item = &'a N8>>(iter: I) -> N8 {
+        iter.copied().sum()
+    }
+}
+
+impl Product for N8 {
+    fn product<I: Iterator<Item = N8>>(iter: I) -> N8 {
+        iter.fold(<N8 as KarccInt>::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a N8> for N8 {
+    fn product<I: Iterator<Item = &'a N8>>(iter: I) -> N8 {
+        iter.copied().product()
+    }
+}
+
+impl N8 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &N8) -> Bool {
+        Bool::new(self == other)
+    }
+
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &N8) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
+
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &N8) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &N8) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &N8) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
+    }
+}
+
+impl N8 {
+    /// Magnitude comparator built from gates, returning `(lt, eq, gt)`.
+    ///
+    /// Scans from the most significant bit: `eq` stays set while every pair of
+    /// bits XNORs to one, and the first differing pair decides `lt` or `gt`.
+    pub fn compare_gates(&self, other: &N8) -> (Bool, Bool, Bool) {
+        let mut lt = Bit::Zero;
+        let mut eq = Bit::One;
+        let mut gt = Bit::Zero;
+        for i in (0..8).rev() {
+            let (a, b) = (self.bits[i], other.bits[i]);
+            gt |= eq & a & !b;
+            lt |= eq & !a & b;
+            eq &= a.xnor(&b);
+        }
+        (lt.as_bool(), eq.as_bool(), gt.as_bool())
+    }
+}
+
+impl N8 {
+    /// Wrapping shift left: `n` is taken modulo 8, matching `u8::wrapping_shl`.
+    ///
+    /// The `<<` operator clears the value once the shift reaches 8; here a
+    /// shift by 8 leaves `self` unchanged.
+    pub fn wrapping_shl(self, n: u32) -> N8 {
+        u8::from(self).wrapping_shl(n).into()
+    }
+
+    /// Wrapping shift right: `n` is taken modulo 8, matching `u8::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> N8 {
+        u8::from(self).wrapping_shr(n).into()
+    }
+}
+
+impl N8 {
+    /// Returns the value as `u8`; same as `u8::from(self)`.
+    pub fn to_u8(self) -> u8 {
+        u8::from(self)
+    }
+}
+
+impl N8 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
+    }
+}
+
+// -------------------- N16 --------------------
+
+/// Unsigned 16-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct N16 {
+    bits: [Bit; 16],
+}
+
+impl From<u16> for N16 {
+    fn from(value: u16) -> Self {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+        }
+        N16 { bits }
+    }
+}
+
+impl From<N16> for u16 {
+    fn from(value: N16) -> Self {
+        let mut result = 0u16;
+        for i in 0..16 {
+            if value.bits[i] == Bit::One {
+                result |= 1 << i;
+            }
+        }
+        result
+    }
+}
+
+impl Sub for N16 {
+    type Output = N16;
+
+    fn sub(self, other: N16) -> N16 {
+        u16::from(self).wrapping_sub(u16::from(other)).into()
+    }
+}
+
+impl SubAssign for N16 {
+    fn sub_assign(&mut self, other: N16) {
+        *self = *self - other;
+    }
+}
+
+impl Add for N16 {
+    type Output = N16;
+
+    fn add(self, other: N16) -> N16 {
+        u16::from(self).wrapping_add(u16::from(other)).into()
+    }
+}
+
+impl AddAssign for N16 {
+    fn add_assign(&mut self, other: N16) {
+        *self = *self + other;
+    }
+}
+
+/// Widening multiplication: the full 32-bit product is returned so that no
+/// information is lost. Use `N16::wrapping_mul` for a 16-bit result.
+impl Mul for N16 {
+    type Output = N32;
+
+    fn mul(self, other: N16) -> N32 {
+        (u16::from(self) as u32 * u16::from(other) as u32).into()
+    }
+}
+
+/// Shifts along the bit array like `N8`; shifting by 16 or more yields zero.
+impl Shr<u8> for N16 {
+    type Output = N16;
+
+    fn shr(self, shift: u8) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        let shift = shift as usize;
+        if shift >= 16 {
+            return N16 { bits };
+        }
+        bits[..16 - shift].copy_from_slice(&self.bits[shift..]);
+        N16 { bits }
+    }
+}
+
+/// Shifts along the bit array like `N8`; shifting by 16 or more yields zero.
+impl Shl<u8> for N16 {
+    type Output = N16;
+
+    fn shl(self, shift: u8) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        let shift = shift as usize;
+        if shift >= 16 {
+            return N16 { bits };
+        }
+        bits[shift..].copy_from_slice(&self.bits[..16 - shift]);
+        N16 { bits }
+    }
+}
+
+impl ShlAssign<u8> for N16 {
+    fn shl_assign(&mut self, shift: u8) {
+        *self = *self << shift;
+    }
+}
+
+impl ShrAssign<u8> for N16 {
+    fn shr_assign(&mut self, shift: u8) {
+        *self = *self >> shift;
+    }
+}
+
+impl Div for N16 {
+    type Output = N16;
+
+    fn div(self, other: N16) -> N16 {
+        (u16::from(self) / u16::from(other)).into()
+    }
+}
+
+impl PartialOrd for N16 {
+    fn partial_cmp(&self, other: &N16) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for N16 {
+    fn cmp(&self, other: &N16) -> Ordering {
+        u16::from(*self).cmp(&u16::from(*other))
+    }
+}
+
+impl N16 {
+    /// Creates a new `N16` from an array of 16 bits.
+    pub fn new(bits: [Bit; 16]) -> Self {
+        N16 { bits }
+    }
+    /// Returns a zero value for N16.
+    pub fn zero() -> Self {
+        N16 {
+            bits: [Bit::Zero; 16],
+        }
+    }
+}
+
+impl BitCount for N16 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::Zero).count() as u32
+    }
+}
+
+impl BitwiseReverse for N16 {
+    fn reverse_bits(&mut self) {
+        self.bits.reverse();
+    }
+}
+
+impl Display for N16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u16::from(*self))
+    }
+}
+
+/// Shows the decimal value followed by the bits, MSB first.
+impl fmt::Debug for N16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = u16::from(*self);
+        write!(f, "N16({} = {:#018b})", value, value)
+    }
+}
+
+impl N16 {
+    /// Addition returning the wrapped sum and whether an overflow occurred.
+    pub fn overflowing_add(self, other: N16) -> (N16, Bool) {
+        let (result, overflow) = u16::from(self).overflowing_add(u16::from(other));
+        (result.into(), Bool::new(overflow))
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, other: N16) -> Option<N16> {
+        u16::from(self).checked_add(u16::from(other)).map(N16::from)
+    }
+
+    /// Checked subtraction. Returns `None` on underflow.
+    pub fn checked_sub(self, other: N16) -> Option<N16> {
+        u16::from(self).checked_sub(u16::from(other)).map(N16::from)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, other: N16) -> Option<N16> {
+        u16::from(self).checked_mul(u16::from(other)).map(N16::from)
+    }
+}
+
+impl N16 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: N16) -> N16 {
+        u16::from(self).saturating_add(u16::from(other)).into()
+    }
+
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: N16) -> N16 {
+        u16::from(self).saturating_sub(u16::from(other)).into()
+    }
+}
+
+impl BitwiseRotate for N16 {
+    fn rotate_left(&mut self, n: u32) {
+        let val = u16::from(*self);
+        let rotated = val.rotate_left(n);
+        *self = rotated.into();
+    }
+    fn rotate_right(&mut self, n: u32) {
+        let val = u16::from(*self);
+        let rotated = val.rotate_right(n);
+        *self = rotated.into();
+    }
+}
+
+impl fmt::LowerHex for N16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&u16::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for N16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&u16::from(*self), f)
+    }
+}
+
+impl fmt::Binary for N16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&u16::from(*self), f)
+    }
+}
+
+impl fmt::Octal for N16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&u16::from(*self), f)
+    }
+}
+
+/// Parses an MSB-first binary digit string, matching `N8`'s convention.
+impl FromStr for N16 {
+    type Err = ParseBitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(N16 {
+            bits: parse_bits(s)?,
+        })
+    }
+}
+
+impl From<N8> for N16 {
+    fn from(value: N8) -> Self {
+        let mut bits = [Bit::Zero; 16];
+        bits[..8].copy_from_slice(&value.bits);
+        N16 { bits }
+    }
+}
+
+impl TryFrom<N32> for N16 {
+    type Error = ConversionError;
+
+    fn try_from(value: N32) -> Result<Self, Self::Error> {
+        if value.bits[16..].contains(&Bit::One) {
+            return Err(ConversionError);
+        }
+        let mut bits = [Bit::Zero; 16];
+        bits.copy_from_slice(&value.bits[..16]);
+        Ok(N16 { bits })
+    }
+}
+
+impl N16 {
+    /// Builds an `N16` from a high and a low byte (big-endian argument order):
+    /// `high` supplies bits 8–15 and `low` supplies bits 0–7.
+    pub fn from_bytes(high: N8, low: N8) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        bits[..8].copy_from_slice(&low.bits);
+        bits[8..].copy_from_slice(&high.bits);
+        N16 { bits }
+    }
+
+    /// Splits the value into `(high, low)` bytes, the inverse of `from_bytes`.
+    pub fn to_bytes(self) -> (N8, N8) {
+        let mut high = [Bit::Zero; 8];
+        let mut low = [Bit::Zero; 8];
+        low.copy_from_slice(&self.bits[..8]);
+        high.copy_from_slice(&self.bits[8..]);
+        (N8::new(high), N8::new(low))
+    }
+}
+
+impl BitScan for N16 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
+    }
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl N16 {
+    /// Returns `Bool::True` if exactly one bit is set. Zero is not a power of two.
+    pub fn is_power_of_two(&self) -> Bool {
+        Bool::new(self.count_ones() == 1)
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// Zero maps to one. If the result does not fit, it wraps to zero, which is
+    /// what the primitive `next_power_of_two` does in release builds.
+    pub fn next_power_of_two(self) -> N16 {
+        u16::from(self)
+            .checked_next_power_of_two()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+impl N16 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> N16 {
+        let mut result = N16::from(1);
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl Not for N16 {
+    type Output = N16;
+
+    fn not(self) -> Self::Output {
+        N16 {
+            bits: self.bits.map(|bit| !bit),
+        }
+    }
+}
+
+impl core::ops::BitAnd for N16 {
+    type Output = N16;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
+            bits[i] = self.bits[i] & other.bits[i];
+        }
+        N16 { bits }
+    }
+}
+
+impl core::ops::BitOr for N16 {
+    type Output = N16;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
+            bits[i] = self.bits[i] | other.bits[i];
+        }
+        N16 { bits }
+    }
+}
+
+impl core::ops::BitXor for N16 {
+    type Output = N16;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
+            bits[i] = self.bits[i] ^ other.bits[i];
+        }
+        N16 { bits }
+    }
+}
+
+impl core::ops::BitAndAssign for N16 {
+    fn bitand_assign(&mut self, other: Self) {
+        for i in 0..16 {
+            self.bits[i] &= other.bits[i];
+        }
+    }
+}
+
+impl core::ops::BitOrAssign for N16 {
+    fn bitor_assign(&mut self, other: Self) {
+        for i in 0..16 {
+            self.bits[i] |= other.bits[i];
+        }
+    }
+}
+
+impl core::ops::BitXorAssign for N16 {
+    fn bitxor_assign(&mut self, other: Self) {
+        for i in 0..16 {
+            self.bits[i] ^= other.bits[i];
+        }
+    }
+}
+
+impl MulAssign for N16 {
+    fn mul_assign(&mut self, other: N16) {
+        *self = self.wrapping_mul(other);
+    }
+}
+
+impl DivAssign for N16 {
+    fn div_assign(&mut self, other: N16) {
+        *self = *self / other;
+    }
+}
+
+impl Rem for N16 {
+    type Output = N16;
+
+    fn rem(self, other: N16) -> N16 {
+        (u16::from(self) % u16::from(other)).into()
+    }
+}
+
+impl RemAssign for N16 {
+    fn rem_assign(&mut self, other: N16) {
+        *self = *self % other;
+    }
+}
+
+impl N16 {
+    /// Multiplication truncated to 16 bits, wrapping on overflow.
+    pub fn wrapping_mul(self, other: N16) -> N16 {
+        u16::from(self).wrapping_mul(u16::from(other)).into()
+    }
+}
+
+impl Index<usize> for N16 {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl N16 {
+    /// Returns an iterator over the bits, least significant bit first.
+    pub fn iter_bits(&self) -> core::array::IntoIter<Bit, 16> {
+        self.bits.into_iter()
+    }
+
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = u16::from(*self);
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
+    }
+}
+
+impl IntoIterator for N16 {
+    type Item = Bit;
+    type IntoIter = core::array::IntoIter<Bit, 16>;
+
+    /// Yields the bits least significant first, matching `FromIterator`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits.into_iter()
+    }
+}
+
+impl N16 {
+    /// Shifts left by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 16 (`u16::wrapping_shl`), so
+    /// a shift by 16 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_left_carry(self, n: u32) -> (N16, Bit) {
+        let n = (n % 16) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 16];
+        bits[n..].copy_from_slice(&self.bits[..16 - n]);
+        (N16 { bits }, self.bits[16 - n])
+    }
+
+    /// Logical right shift by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 16 (`u16::wrapping_shr`), so
+    /// a shift by 16 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_right_carry(self, n: u32) -> (N16, Bit) {
+        let n = (n % 16) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 16];
+        bits[..16 - n].copy_from_slice(&self.bits[n..]);
+        (N16 { bits }, self.bits[n - 1])
+    }
+}
+
+impl N16 {
+    /// Full 32-bit product; the same as `*`, which already widens for `N16`.
+    pub fn widening_mul(self, rhs: N16) -> N32 {
+        self * rhs
+    }
+}
+
+impl KarccInt for N16 {
+    type Primitive = u16;
+
+    const ZERO: Self = N16::from_u16(0);
+    const ONE: Self = N16::from_u16(1);
+    const MIN: Self = N16::from_u16(u16::MIN);
+    const MAX: Self = N16::from_u16(u16::MAX);
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        N16::wrapping_mul(self, rhs)
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        N16::checked_add(self, rhs)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        N16::checked_sub(self, rhs)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        N16::checked_mul(self, rhs)
+    }
+}
+
+impl N16 {
+    /// Returns the value as bytes in little-endian order, as `u16::to_le_bytes`.
+    pub fn to_le_bytes(self) -> [u8; 2] {
+        u16::from(self).to_le_bytes()
+    }
+
+    /// Returns the value as bytes in big-endian order, as `u16::to_be_bytes`.
+    pub fn to_be_bytes(self) -> [u8; 2] {
+        u16::from(self).to_be_bytes()
+    }
+
+    /// Builds a value from little-endian bytes, as `u16::from_le_bytes`.
+    pub fn from_le_bytes(bytes: [u8; 2]) -> N16 {
+        u16::from_le_bytes(bytes).into()
+    }
+
+    /// Builds a value from big-endian bytes, as `u16::from_be_bytes`.
+    pub fn from_be_bytes(bytes: [u8; 2]) -> N16 {
+        u16::from_be_bytes(bytes).into()
+    }
+}
+
+impl N16 {
+    /// Reverses the order of the bytes, keeping the bits within each byte in place.
+    pub fn swap_bytes(self) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        for (i, byte) in self.bits.chunks(8).enumerate() {
+            let start = 16 - 8 * (i + 1);
+            bits[start..start + 8].copy_from_slice(byte);
+        }
+        N16 { bits }
+    }
+}
+
+impl N16 {
+    /// Encodes the value as reflected binary Gray code, `x ^ (x >> 1)`.
+    pub fn to_gray(self) -> N16 {
+        self ^ (self >> 1)
+    }
+
+    /// Decodes a Gray code value, the inverse of `to_gray`.
+    pub fn from_gray(self) -> N16 {
+        let mut value = self;
+        let mut shifted = self >> 1;
+        while shifted != N16::ZERO {
+            value ^= shifted;
+            shifted >>= 1;
+        }
+        value
+    }
+}
+
+impl Parity for N16 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
+
+impl N16 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &N16) -> u32 {
+        (*self ^ *other).count_ones()
+    }
+}
+
+impl N16 {
+    /// Creates an `N16` from a `u16` in a const context.
+    pub const fn from_u16(value: u16) -> Self {
+        let mut bits = [Bit::Zero; 16];
+        let mut i = 0;
+        while i < 16 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+            i += 1;
+        }
+        N16 { bits }
+    }
+
+    /// Bitwise AND usable in const contexts.
+    pub const fn bitand_const(self, other: N16) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        let mut i = 0;
+        while i < 16 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::One, Bit::One) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N16 { bits }
+    }
+
+    /// Bitwise OR usable in const contexts.
+    pub const fn bitor_const(self, other: N16) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        let mut i = 0;
+        while i < 16 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::Zero) => Bit::Zero,
+                _ => Bit::One,
+            };
+            i += 1;
+        }
+        N16 { bits }
+    }
+
+    /// Bitwise XOR usable in const contexts.
+    pub const fn bitxor_const(self, other: N16) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        let mut i = 0;
+        while i < 16 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::One) | (Bit::One, Bit::Zero) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N16 { bits }
+    }
+
+    /// Bitwise NOT usable in const contexts.
+    pub const fn not_const(self) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        let mut i = 0;
+        while i < 16 {
+            bits[i] = match self.bits[i] {
+                Bit::Zero => Bit::One,
+                Bit::One => Bit::Zero,
+            };
+            i += 1;
+        }
+        N16 { bits }
+    }
+}
+
+impl N16 {
+    /// Two's-complement negation, `!self + 1` wrapping modulo 2^16.
+    pub fn wrapping_neg(self) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        let mut carry = Bit::One;
+        for i in 0..16 {
+            let (sum, new_carry) = Bit::half_adder(!self.bits[i], carry);
+            bits[i] = sum;
+            carry = new_carry;
+        }
+        N16 { bits }
+    }
+}
+
+impl N16 {
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: N16) -> Option<N16> {
+        u16::from(self).checked_div(u16::from(rhs)).map(N16::from)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: N16) -> Option<N16> {
+        u16::from(self).checked_rem(u16::from(rhs)).map(N16::from)
+    }
+}
+
+impl N16 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(16 + 16 / group.max(1));
+        for i in (0..16).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
+            }
+        }
+        text
+    }
+}
+
+impl N16 {
+    /// Parses a hexadecimal string such as `"0xFF"` or `"ff"`.
+    pub fn from_hex_str(s: &str) -> Result<N16, ParseBitsError> {
+        Ok(N16 {
+            bits: parse_hex_bits(s)?,
+        })
+    }
+}
+
+impl N16 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 16.
+    pub fn bit_range(&self, start: usize, len: usize) -> N16 {
+        assert!(
+            start + len <= 16,
+            "bit range {}..{} out of range for 16 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 16];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        N16 { bits }
+    }
+}
+
+impl N16 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 16 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> N16 {
+        assert!(index < 16, "bit index {} out of range for 16 bits", index);
+        self.bits[index] = bit;
+        self
+    }
+
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 16 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 16, "bit index {} out of range for 16 bits", index);
+        self.bits[index] = bit;
+    }
+
+    /// Clears the bit at `index`. Panics if `index` is 16 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 16, "bit index {} out of range for 16 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
+
+    /// Flips the bit at `index`. Panics if `index` is 16 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 16, "bit index {} out of range for 16 bits", index);
+        self.bits[index] = !self.bits[index];
+    }
+}
+
+impl N16 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: N16) -> N16 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of two values.
+    pub fn max(self, other: N16) -> N16 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: N16, hi: N16) -> N16 {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+impl Sum for N16 {
+    fn sum<I: Iterator<Item = N16>>(iter: I) -> N16 {
+        iter.fold(<N16 as KarccInt>::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a N16> for N16 {
+    fn sum<I: Iterator<Item = &'a N16>>(iter: I) -> N16 {
+        iter.copied().sum()
+    }
+}
+
+impl Product for N16 {
+    fn product<I: Iterator<Item = N16>>(iter: I) -> N16 {
+        iter.fold(<N16 as KarccInt>::ONE, |acc, x| acc.wrapping_mul(x))
+    }
+}
+
+impl<'a> Product<&'a N16> for N16 {
+    fn product<I: Iterator<Item = &'a N16>>(iter: I) -> N16 {
+        iter.copied().product()
+    }
+}
+
+impl N16 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &N16) -> Bool {
+        Bool::new(self == other)
+    }
+
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &N16) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
+
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &N16) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &N16) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &N16) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
+    }
+}
+
+impl FromIterator<Bit> for N16 {
+    fn from_iter<I: IntoIterator<Item = Bit>>(iter: I) -> Self {
+        let mut bits = [Bit::Zero; 16];
+        for (i, bit) in iter.into_iter().enumerate().take(16) {
+            bits[i] = bit;
+        }
+        N16 { bits }
+    }
+}
+
+/// Interprets the array least significant bit first: element 0 becomes bit 0.
+impl From<[bool; 16]> for N16 {
+    fn from(value: [bool; 16]) -> Self {
+        let mut bits = [Bit::Zero; 16];
+        for (i, &b) in value.iter().enumerate() {
+            if b {
+                bits[i] = Bit::One;
+            }
+        }
+        N16 { bits }
+    }
+}
+
+impl N16 {
+    /// Wrapping shift left: `n` is taken modulo 16, matching `u16::wrapping_shl`.
+    ///
+    /// The `<<` operator clears the value once the shift reaches 16; here a
+    /// shift by 16 leaves `self` unchanged.
+    pub fn wrapping_shl(self, n: u32) -> N16 {
+        u16::from(self).wrapping_shl(n).into()
+    }
+
+    /// Wrapping shift right: `n` is taken modulo 16, matching `u16::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> N16 {
+        u16::from(self).wrapping_shr(n).into()
+    }
+}
+
+impl N16 {
+    /// Returns the value as `u16`; same as `u16::from(self)`.
+    pub fn to_u16(self) -> u16 {
+        u16::from(self)
+    }
+}
+
+impl N16 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
+    }
+}
+
+// ---------------- N32 --------------------
+
+/// Unsigned 32-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct N32 {
+    bits: [Bit; 32],
+}
+
+impl N32 {
+    /// Creates a new `N32` from an array of 32 bits.
+    pub fn new(bits: [Bit; 32]) -> Self {
+        N32 { bits }
+    }
+    /// Returns a zero value for N32.
+    pub fn zero() -> Self {
+        N32 {
+            bits: [Bit::Zero; 32],
+        }
+    }
+}
+
+impl BitCount for N32 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::Zero).count() as u32
+    }
+}
+
+impl Display for N32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u32::from(*self))
+    }
+}
+
+/// Shows the decimal value followed by the bits, MSB first.
+impl fmt::Debug for N32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = u32::from(*self);
+        write!(f, "N32({} = {:#034b})", value, value)
+    }
+}
+
+impl From<u32> for N32 {
+    fn from(value: u32) -> Self {
+        let mut bits = [Bit::Zero; 32];
+        for i in 0..32 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+        }
+        N32 { bits }
+    }
+}
+
+impl From<N32> for u32 {
+    fn from(value: N32) -> Self {
+        value
+            .bits
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, &bit)| acc | (u8::from(bit) as u32) << i)
+    }
+}
+
+impl Add for N32 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        u32::from(self).wrapping_add(u32::from(other)).into()
+    }
+}
+
+impl Sub for N32 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        u32::from(self).wrapping_sub(u32::from(other)).into()
+    }
+}
+
+impl SubAssign for N32 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl AddAssign for N32 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Mul for N32 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        u32::from(self).wrapping_mul(u32::from(other)).into()
+    }
+}
+
+impl MulAssign for N32 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Div for N32 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        (u32::from(self) / u32::from(other)).into()
+    }
+}
+
+impl DivAssign for N32 {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Rem for N32 {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        (u32::from(self) % u32::from(other)).into()
+    }
+}
+
+impl RemAssign for N32 {
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
+
+impl N32 {
+    /// Addition returning the wrapped sum and whether an overflow occurred.
+    pub fn overflowing_add(self, other: N32) -> (N32, Bool) {
+        let (result, overflow) = u32::from(self).overflowing_add(u32::from(other));
+        (result.into(), Bool::new(overflow))
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, other: N32) -> Option<N32> {
+        u32::from(self).checked_add(u32::from(other)).map(N32::from)
+    }
+
+    /// Checked subtraction. Returns `None` on underflow.
+    pub fn checked_sub(self, other: N32) -> Option<N32> {
+        u32::from(self).checked_sub(u32::from(other)).map(N32::from)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, other: N32) -> Option<N32> {
+        u32::from(self).checked_mul(u32::from(other)).map(N32::from)
+    }
+}
+
+impl N32 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: N32) -> N32 {
+        u32::from(self).saturating_add(u32::from(other)).into()
+    }
+
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: N32) -> N32 {
+        u32::from(self).saturating_sub(u32::from(other)).into()
+    }
+}
+
+impl BitwiseReverse for N32 {
+    fn reverse_bits(&mut self) {
+        self.bits.reverse();
+    }
+}
+
+impl BitwiseRotate for N32 {
+    fn rotate_left(&mut self, n: u32) {
+        let val = u32::from(*self);
+        let rotated = val.rotate_left(n);
+        *self = rotated.into();
+    }
+    fn rotate_right(&mut self, n: u32) {
+        let val = u32::from(*self);
+        let rotated = val.rotate_right(n);
+        *self = rotated.into();
+    }
+}
+
+impl fmt::LowerHex for N32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&u32::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for N32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&u32::from(*self), f)
+    }
+}
+
+impl fmt::Binary for N32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&u32::from(*self), f)
+    }
+}
+
+impl fmt::Octal for N32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&u32::from(*self), f)
+    }
+}
+
+/// Parses an MSB-first binary digit string, matching `N8`'s convention.
+impl FromStr for N32 {
+    type Err = ParseBitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(N32 {
+            bits: parse_bits(s)?,
+        })
+    }
+}
+
+impl From<N8> for N32 {
+    fn from(value: N8) -> Self {
+        let mut bits = [Bit::Zero; 32];
+        bits[..8].copy_from_slice(&value.bits);
+        N32 { bits }
+    }
+}
+
+impl From<N16> for N32 {
+    fn from(value: N16) -> Self {
+        let mut bits = [Bit::Zero; 32];
+        bits[..16].copy_from_slice(&value.bits);
+        N32 { bits }
+    }
+}
+
+impl TryFrom<N64> for N32 {
+    type Error = ConversionError;
+
+    fn try_from(value: N64) -> Result<Self, Self::Error> {
+        if value.bits[32..].contains(&Bit::One) {
+            return Err(ConversionError);
+        }
+        let mut bits = [Bit::Zero; 32];
+        bits.copy_from_slice(&value.bits[..32]);
+        Ok(N32 { bits })
+    }
+}
+
+impl BitScan for N32 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
+    }
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl N32 {
+    /// Returns `Bool::True` if exactly one bit is set. Zero is not a power of two.
+    pub fn is_power_of_two(&self) -> Bool {
+        Bool::new(self.count_ones() == 1)
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// Zero maps to one. If the result does not fit, it wraps to zero, which is
+    /// what the primitive `next_power_of_two` does in release builds.
+    pub fn next_power_of_two(self) -> N32 {
+        u32::from(self)
+            .checked_next_power_of_two()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+impl N32 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> N32 {
+        let mut result = N32::from(1);
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl PartialOrd for N32 {
+    fn partial_cmp(&self, other: &N32) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for N32 {
+    fn cmp(&self, other: &N32) -> Ordering {
+        u32::from(*self).cmp(&u32::from(*other))
+    }
+}
+
+impl Not for N32 {
+    type Output = N32;
+
+    fn not(self) -> Self::Output {
+        N32 {
+            bits: self.bits.map(|bit| !bit),
+        }
+    }
+}
+
+impl core::ops::BitAnd for N32 {
+    type Output = N32;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 32];
+        for i in 0..32 {
+            bits[i] = self.bits[i] & other.bits[i];
+        }
+        N32 { bits }
+    }
+}
+
+impl core::ops::BitOr for N32 {
+    type Output = N32;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 32];
+        for i in 0..32 {
+            bits[i] = self.bits[i] | other.bits[i];
+        }
+        N32 { bits }
+    }
+}
+
+impl core::ops::BitXor for N32 {
+    type Output = N32;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 32];
+        for i in 0..32 {
+            bits[i] = self.bits[i] ^ other.bits[i];
+        }
+        N32 { bits }
+    }
+}
+
+impl core::ops::BitAndAssign for N32 {
+    fn bitand_assign(&mut self, other: Self) {
+        for i in 0..32 {
+            self.bits[i] &= other.bits[i];
+        }
+    }
+}
+
+impl core::ops::BitOrAssign for N32 {
+    fn bitor_assign(&mut self, other: Self) {
+        for i in 0..32 {
+            self.bits[i] |= other.bits[i];
+        }
+    }
+}
+
+impl core::ops::BitXorAssign for N32 {
+    fn bitxor_assign(&mut self, other: Self) {
+        for i in 0..32 {
+            self.bits[i] ^= other.bits[i];
+        }
+    }
+}
+
+impl Index<usize> for N32 {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl N32 {
+    /// Returns an iterator over the bits, least significant bit first.
+    pub fn iter_bits(&self) -> core::array::IntoIter<Bit, 32> {
+        self.bits.into_iter()
+    }
+
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = u32::from(*self);
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
+    }
+}
+
+impl IntoIterator for N32 {
+    type Item = Bit;
+    type IntoIter = core::array::IntoIter<Bit, 32>;
+
+    /// Yields the bits least significant first, matching `FromIterator`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits.into_iter()
+    }
+}
+
+impl N32 {
+    /// Shifts left by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 32 (`u32::wrapping_shl`), so
+    /// a shift by 32 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_left_carry(self, n: u32) -> (N32, Bit) {
+        let n = (n % 32) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 32];
+        bits[n..].copy_from_slice(&self.bits[..32 - n]);
+        (N32 { bits }, self.bits[32 - n])
+    }
+
+    /// Logical right shift by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 32 (`u32::wrapping_shr`), so
+    /// a shift by 32 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_right_carry(self, n: u32) -> (N32, Bit) {
+        let n = (n % 32) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 32];
+        bits[..32 - n].copy_from_slice(&self.bits[n..]);
+        (N32 { bits }, self.bits[n - 1])
+    }
+}
+
+impl KarccInt for N32 {
+    type Primitive = u32;
+
+    const ZERO: Self = N32::from_u32(0);
+    const ONE: Self = N32::from_u32(1);
+    const MIN: Self = N32::from_u32(u32::MIN);
+    const MAX: Self = N32::from_u32(u32::MAX);
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        N32::checked_add(self, rhs)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        N32::checked_sub(self, rhs)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        N32::checked_mul(self, rhs)
+    }
+}
+
+impl N32 {
+    /// Returns the value as bytes in little-endian order, as `u32::to_le_bytes`.
+    pub fn to_le_bytes(self) -> [u8; 4] {
+        u32::from(self).to_le_bytes()
+    }
+
+    /// Returns the value as bytes in big-endian order, as `u32::to_be_bytes`.
+    pub fn to_be_bytes(self) -> [u8; 4] {
+        u32::from(self).to_be_bytes()
+    }
+
+    /// Builds a value from little-endian bytes, as `u32::from_le_bytes`.
+    pub fn from_le_bytes(bytes: [u8; 4]) -> N32 {
+        u32::from_le_bytes(bytes).into()
+    }
+
+    /// Builds a value from big-endian bytes, as `u32::from_be_bytes`.
+    pub fn from_be_bytes(bytes: [u8; 4]) -> N32 {
+        u32::from_be_bytes(bytes).into()
+    }
+}
+
+impl N32 {
+    /// Reverses the order of the bytes, keeping the bits within each byte in place.
+    pub fn swap_bytes(self) -> N32 {
+        let mut bits = [Bit::Zero; 32];
+        for (i, byte) in self.bits.chunks(8).enumerate() {
+            let start = 32 - 8 * (i + 1);
+            bits[start..start + 8].copy_from_slice(byte);
+        }
+        N32 { bits }
+    }
+}
+
+impl N32 {
+    /// Encodes the value as reflected binary Gray code, `x ^ (x >> 1)`.
+    pub fn to_gray(self) -> N32 {
+        self ^ (self.shift_right_carry(1).0)
+    }
+
+    /// Decodes a Gray code value, the inverse of `to_gray`.
+    pub fn from_gray(self) -> N32 {
+        let mut value = self;
+        let mut shifted = self.shift_right_carry(1).0;
+        while shifted != N32::ZERO {
+            value ^= shifted;
+            shifted = shifted.shift_right_carry(1).0;
+        }
+        value
+    }
+}
+
+impl Parity for N32 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
+
+impl N32 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &N32) -> u32 {
+        (*self ^ *other).count_ones()
+    }
+}
+
+impl N32 {
+    /// Creates an `N32` from a `u32` in a const context.
+    pub const fn from_u32(value: u32) -> Self {
+        let mut bits = [Bit::Zero; 32];
+        let mut i = 0;
+        while i < 32 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+            i += 1;
+        }
+        N32 { bits }
+    }
+
+    /// Bitwise AND usable in const contexts.
+    pub const fn bitand_const(self, other: N32) -> N32 {
+        let mut bits = [Bit::Zero; 32];
+        let mut i = 0;
+        while i < 32 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::One, Bit::One) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N32 { bits }
+    }
+
+    /// Bitwise OR usable in const contexts.
+    pub const fn bitor_const(self, other: N32) -> N32 {
+        let mut bits = [Bit::Zero; 32];
+        let mut i = 0;
+        while i < 32 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::Zero) => Bit::Zero,
+                _ => Bit::One,
+            };
+            i += 1;
+        }
+        N32 { bits }
+    }
+
+    /// Bitwise XOR usable in const contexts.
+    pub const fn bitxor_const(self, other: N32) -> N32 {
+        let mut bits = [Bit::Zero; 32];
+        let mut i = 0;
+        while i < 32 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::One) | (Bit::One, Bit::Zero) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N32 { bits }
+    }
+
+    /// Bitwise NOT usable in const contexts.
+    pub const fn not_const(self) -> N32 {
+        let mut bits = [Bit::Zero; 32];
+        let mut i = 0;
+        while i < 32 {
+            bits[i] = match self.bits[i] {
+                Bit::Zero => Bit::One,
+                Bit::One => Bit::Zero,
+            };
+            i += 1;
+        }
+        N32 { bits }
+    }
+}
+
+impl N32 {
+    /// Two's-complement negation, `!self + 1` wrapping modulo 2^32.
+    pub fn wrapping_neg(self) -> N32 {
+        let mut bits = [Bit::Zero; 32];
+        let mut carry = Bit::One;
+        for i in 0..32 {
+            let (sum, new_carry) = Bit::half_adder(!self.bits[i], carry);
+            bits[i] = sum;
+            carry = new_carry;
+        }
+        N32 { bits }
+    }
+}
+
+impl N32 {
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: N32) -> Option<N32> {
+        u32::from(self).checked_div(u32::from(rhs)).map(N32::from)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: N32) -> Option<N32> {
+        u32::from(self).checked_rem(u32::from(rhs)).map(N32::from)
+    }
+}
+
+impl N32 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(32 + 32 / group.max(1));
+        for i in (0..32).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
+            }
+        }
+        text
+    }
+}
+
+impl N32 {
+    /// Parses a hexadecimal string such as `"0xFF"` or `"ff"`.
+    pub fn from_hex_str(s: &str) -> Result<N32, ParseBitsError> {
+        Ok(N32 {
+            bits: parse_hex_bits(s)?,
+        })
+    }
+}
+
+impl N32 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 32.
+    pub fn bit_range(&self, start: usize, len: usize) -> N32 {
+        assert!(
+            start + len <= 32,
+            "bit range {}..{} out of range for 32 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 32];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        N32 { bits }
+    }
+}
+
+impl N32 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 32 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> N32 {
+        assert!(index < 32, "bit index {} out of range for 32 bits", index);
+        self.bits[index] = bit;
+        self
+    }
+
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 32 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 32, "bit index {} out of range for 32 bits", index);
+        self.bits[index] = bit;
+    }
+
+    /// Clears the bit at `index`. Panics if `index` is 32 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 32, "bit index {} out of range for 32 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
+
+    /// Flips the bit at `index`. Panics if `index` is 32 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 32, "bit index {} out of range for 32 bits", index);
+        self.bits[index] = !self.bits[index];
+    }
+}
+
+impl N32 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: N32) -> N32 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of two values.
+    pub fn max(self, other: N32) -> N32 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: N32, hi: N32) -> N32 {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+impl Sum for N32 {
+    fn sum<I: Iterator<Item = N32>>(iter: I) -> N32 {
+        iter.fold(<N32 as KarccInt>::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a N32> for N32 {
+    fn sum<I: Iterator<Item = &'a N32>>(iter: I) -> N32 {
+        iter.copied().sum()
+    }
+}
+
+impl Product for N32 {
+    fn product<I: Iterator<Item = N32>>(iter: I) -> N32 {
+        iter.fold(<N32 as KarccInt>::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a N32> for N32 {
+    fn product<I: Iterator<Item = &'a N32>>(iter: I) -> N32 {
+        iter.copied().product()
+    }
+}
+
+impl N32 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &N32) -> Bool {
+        Bool::new(self == other)
+    }
+
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &N32) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
+
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &N32) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &N32) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &N32) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
+    }
+}
+
+impl FromIterator<Bit> for N32 {
+    fn from_iter<I: IntoIterator<Item = Bit>>(iter: I) -> Self {
+        let mut bits = [Bit::Zero; 32];
+        for (i, bit) in iter.into_iter().enumerate().take(32) {
+            bits[i] = bit;
+        }
+        N32 { bits }
+    }
+}
+
+/// Interprets the array least significant bit first: element 0 becomes bit 0.
+impl From<[bool; 32]> for N32 {
+    fn from(value: [bool; 32]) -> Self {
+        let mut bits = [Bit::Zero; 32];
+        for (i, &b) in value.iter().enumerate() {
+            if b {
+                bits[i] = Bit::One;
+            }
+        }
+        N32 { bits }
+    }
+}
+
+impl N32 {
+    /// Wrapping shift left: `n` is taken modulo 32, matching `u32::wrapping_shl`.
+    ///
+    /// `N32` has no `<<` operator; this and `shift_left_carry` are its left shifts.
+    pub fn wrapping_shl(self, n: u32) -> N32 {
+        u32::from(self).wrapping_shl(n).into()
+    }
+
+    /// Wrapping shift right: `n` is taken modulo 32, matching `u32::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> N32 {
+        u32::from(self).wrapping_shr(n).into()
+    }
+}
+
+/// Fails only on targets where `usize` is narrower than 32 bits.
+impl TryFrom<N32> for usize {
+    type Error = ConversionError;
+
+    fn try_from(value: N32) -> Result<Self, Self::Error> {
+        usize::try_from(u32::from(value)).map_err(|_| ConversionError)
+    }
+}
+
+/// Fails on 64-bit targets when the value does not fit in 32 bits.
+impl TryFrom<usize> for N32 {
+    type Error = ConversionError;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        u32::try_from(value)
+            .map(Into::into)
+            .map_err(|_| ConversionError)
+    }
+}
+
+impl N32 {
+    /// Converts to `usize` for indexing and length arithmetic.
+    ///
+    /// Never panics on 32- or 64-bit targets.
+    pub fn to_usize(self) -> usize {
+        usize::try_from(self).expect("value does not fit in usize on this target")
+    }
+}
+
+impl N32 {
+    /// Returns the value as `u32`; same as `u32::from(self)`.
+    pub fn to_u32(self) -> u32 {
+        u32::from(self)
+    }
+}
+
+impl N32 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
+    }
+}
+
+// ---------------- N64 --------------------
+
+/// Unsigned 64-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct N64 {
+    bits: [Bit; 64],
+}
+
+impl From<u64> for N64 {
+    fn from(value: u64) -> Self {
+        let mut bits = [Bit::Zero; 64];
+        for i in 0..64 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+        }
+        N64 { bits }
+    }
+}
+
+impl From<N64> for u64 {
+    fn from(value: N64) -> Self {
+        value
+            .bits
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, &bit)| acc | (u8::from(bit) as u64) << i)
+    }
+}
+
+impl Add for N64 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        u64::from(self).wrapping_add(u64::from(other)).into()
+    }
+}
+
+impl Sub for N64 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        u64::from(self).wrapping_sub(u64::from(other)).into()
+    }
+}
+
+impl Mul for N64 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        u64::from(self).wrapping_mul(u64::from(other)).into()
+    }
+}
+
+impl Div for N64 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        (u64::from(self) / u64::from(other)).into()
+    }
+}
+
+impl AddAssign for N64 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for N64 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl BitCount for N64 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        64 - self.count_ones()
+    }
+}
+
+impl Display for N64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u64::from(*self))
+    }
+}
+
+/// Shows the decimal value followed by the bits, MSB first.
+impl fmt::Debug for N64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = u64::from(*self);
+        write!(f, "N64({} = {:#066b})", value, value)
+    }
+}
+
+impl N64 {
+    /// Addition returning the wrapped sum and whether an overflow occurred.
+    pub fn overflowing_add(self, other: N64) -> (N64, Bool) {
+        let (result, overflow) = u64::from(self).overflowing_add(u64::from(other));
+        (result.into(), Bool::new(overflow))
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, other: N64) -> Option<N64> {
+        u64::from(self).checked_add(u64::from(other)).map(N64::from)
+    }
+
+    /// Checked subtraction. Returns `None` on underflow.
+    pub fn checked_sub(self, other: N64) -> Option<N64> {
+        u64::from(self).checked_sub(u64::from(other)).map(N64::from)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, other: N64) -> Option<N64> {
+        u64::from(self).checked_mul(u64::from(other)).map(N64::from)
+    }
+}
+
+impl N64 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: N64) -> N64 {
+        u64::from(self).saturating_add(u64::from(other)).into()
+    }
+
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: N64) -> N64 {
+        u64::from(self).saturating_sub(u64::from(other)).into()
+    }
+}
+
+impl BitwiseReverse for N64 {
+    fn reverse_bits(&mut self) {
+        self.bits.reverse();
+    }
+}
+
+impl BitwiseRotate for N64 {
+    fn rotate_left(&mut self, n: u32) {
+        let val = u64::from(*self);
+        let rotated = val.rotate_left(n);
+        *self = rotated.into();
+    }
+    fn rotate_right(&mut self, n: u32) {
+        let val = u64::from(*self);
+        let rotated = val.rotate_right(n);
+        *self = rotated.into();
+    }
+}
+
+impl fmt::LowerHex for N64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&u64::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for N64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&u64::from(*self), f)
+    }
+}
+
+impl fmt::Binary for N64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&u64::from(*self), f)
+    }
+}
+
+impl fmt::Octal for N64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&u64::from(*self), f)
+    }
+}
+
+/// Parses an MSB-first binary digit string, matching `N8`'s convention.
+impl FromStr for N64 {
+    type Err = ParseBitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(N64 {
+            bits: parse_bits(s)?,
+        })
+    }
+}
+
+impl From<N8> for N64 {
+    fn from(value: N8) -> Self {
+        let mut bits = [Bit::Zero; 64];
+        bits[..8].copy_from_slice(&value.bits);
+        N64 { bits }
+    }
+}
+
+impl From<N16> for N64 {
+    fn from(value: N16) -> Self {
+        let mut bits = [Bit::Zero; 64];
+        bits[..16].copy_from_slice(&value.bits);
+        N64 { bits }
+    }
+}
+
+impl From<N32> for N64 {
+    fn from(value: N32) -> Self {
+        let mut bits = [Bit::Zero; 64];
+        bits[..32].copy_from_slice(&value.bits);
+        N64 { bits }
+    }
+}
+
+impl BitScan for N64 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
+    }
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl N64 {
+    /// Returns `Bool::True` if exactly one bit is set. Zero is not a power of two.
+    pub fn is_power_of_two(&self) -> Bool {
+        Bool::new(self.count_ones() == 1)
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// Zero maps to one. If the result does not fit, it wraps to zero, which is
+    /// what the primitive `next_power_of_two` does in release builds.
+    pub fn next_power_of_two(self) -> N64 {
+        u64::from(self)
+            .checked_next_power_of_two()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+impl N64 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> N64 {
+        let mut result = N64::from(1);
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl PartialOrd for N64 {
+    fn partial_cmp(&self, other: &N64) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for N64 {
+    fn cmp(&self, other: &N64) -> Ordering {
+        u64::from(*self).cmp(&u64::from(*other))
+    }
+}
+
+impl Not for N64 {
+    type Output = N64;
+
+    fn not(self) -> Self::Output {
+        N64 {
+            bits: self.bits.map(|bit| !bit),
+        }
+    }
+}
+
+impl core::ops::BitAnd for N64 {
+    type Output = N64;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 64];
+        for i in 0..64 {
+            bits[i] = self.bits[i] & other.bits[i];
+        }
+        N64 { bits }
+    }
+}
+
+impl core::ops::BitOr for N64 {
+    type Output = N64;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 64];
+        for i in 0..64 {
+            bits[i] = self.bits[i] | other.bits[i];
+        }
+        N64 { bits }
+    }
+}
+
+impl core::ops::BitXor for N64 {
+    type Output = N64;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 64];
+        for i in 0..64 {
+            bits[i] = self.bits[i] ^ other.bits[i];
+        }
+        N64 { bits }
+    }
+}
+
+impl core::ops::BitAndAssign for N64 {
+    fn bitand_assign(&mut self, other: Self) {
+        for i in 0..64 {
+            self.bits[i] &= other.bits[i];
+        }
+    }
+}
+
+impl core::ops::BitOrAssign for N64 {
+    fn bitor_assign(&mut self, other: Self) {
+        for i in 0..64 {
+            self.bits[i] |= other.bits[i];
+        }
+    }
+}
+
+impl core::ops::BitXorAssign for N64 {
+    fn bitxor_assign(&mut self, other: Self) {
+        for i in 0..64 {
+            self.bits[i] ^= other.bits[i];
+        }
+    }
+}
+
+impl Rem for N64 {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        (u64::from(self) % u64::from(other)).into()
+    }
+}
+
+impl MulAssign for N64 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for N64 {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl RemAssign for N64 {
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
+
+impl Index<usize> for N64 {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl N64 {
+    /// Returns an iterator over the bits, least significant bit first.
+    pub fn iter_bits(&self) -> core::array::IntoIter<Bit, 64> {
+        self.bits.into_iter()
+    }
+
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = u64::from(*self);
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
+    }
+}
+
+impl IntoIterator for N64 {
+    type Item = Bit;
+    type IntoIter = core::array::IntoIter<Bit, 64>;
+
+    /// Yields the bits least significant first, matching `FromIterator`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits.into_iter()
+    }
+}
+
+impl N64 {
+    /// Shifts left by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 64 (`u64::wrapping_shl`), so
+    /// a shift by 64 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_left_carry(self, n: u32) -> (N64, Bit) {
+        let n = (n % 64) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 64];
+        bits[n..].copy_from_slice(&self.bits[..64 - n]);
+        (N64 { bits }, self.bits[64 - n])
+    }
+
+    /// Logical right shift by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 64 (`u64::wrapping_shr`), so
+    /// a shift by 64 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_right_carry(self, n: u32) -> (N64, Bit) {
+        let n = (n % 64) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 64];
+        bits[..64 - n].copy_from_slice(&self.bits[n..]);
+        (N64 { bits }, self.bits[n - 1])
+    }
+}
+
+impl KarccInt for N64 {
+    type Primitive = u64;
+
+    const ZERO: Self = N64::from_u64(0);
+    const ONE: Self = N64::from_u64(1);
+    const MIN: Self = N64::from_u64(u64::MIN);
+    const MAX: Self = N64::from_u64(u64::MAX);
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        N64::checked_add(self, rhs)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        N64::checked_sub(self, rhs)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        N64::checked_mul(self, rhs)
+    }
+}
+
+impl N64 {
+    /// Returns the value as bytes in little-endian order, as `u64::to_le_bytes`.
+    pub fn to_le_bytes(self) -> [u8; 8] {
+        u64::from(self).to_le_bytes()
+    }
+
+    /// Returns the value as bytes in big-endian order, as `u64::to_be_bytes`.
+    pub fn to_be_bytes(self) -> [u8; 8] {
+        u64::from(self).to_be_bytes()
+    }
+
+    /// Builds a value from little-endian bytes, as `u64::from_le_bytes`.
+    pub fn from_le_bytes(bytes: [u8; 8]) -> N64 {
+        u64::from_le_bytes(bytes).into()
+    }
+
+    /// Builds a value from big-endian bytes, as `u64::from_be_bytes`.
+    pub fn from_be_bytes(bytes: [u8; 8]) -> N64 {
+        u64::from_be_bytes(bytes).into()
+    }
+}
+
+impl N64 {
+    /// Reverses the order of the bytes, keeping the bits within each byte in place.
+    pub fn swap_bytes(self) -> N64 {
+        let mut bits = [Bit::Zero; 64];
+        for (i, byte) in self.bits.chunks(8).enumerate() {
+            let start = 64 - 8 * (i + 1);
+            bits[start..start + 8].copy_from_slice(byte);
+        }
+        N64 { bits }
+    }
+}
+
+impl N64 {
+    /// Encodes the value as reflected binary Gray code, `x ^ (x >> 1)`.
+    pub fn to_gray(self) -> N64 {
+        self ^ (self.shift_right_carry(1).0)
+    }
+
+    /// Decodes a Gray code value, the inverse of `to_gray`.
+    pub fn from_gray(self) -> N64 {
+        let mut value = self;
+        let mut shifted = self.shift_right_carry(1).0;
+        while shifted != N64::ZERO {
+            value ^= shifted;
+            shifted = shifted.shift_right_carry(1).0;
+        }
+        value
+    }
+}
+
+impl Parity for N64 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
+
+impl N64 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &N64) -> u32 {
+        (*self ^ *other).count_ones()
+    }
+}
+
+impl N64 {
+    /// Creates an `N64` from a `u64` in a const context.
+    pub const fn from_u64(value: u64) -> Self {
+        let mut bits = [Bit::Zero; 64];
+        let mut i = 0;
+        while i < 64 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+            i += 1;
+        }
+        N64 { bits }
+    }
+
+    /// Bitwise AND usable in const contexts.
+    pub const fn bitand_const(self, other: N64) -> N64 {
+        let mut bits = [Bit::Zero; 64];
+        let mut i = 0;
+        while i < 64 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::One, Bit::One) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N64 { bits }
+    }
+
+    /// Bitwise OR usable in const contexts.
+    pub const fn bitor_const(self, other: N64) -> N64 {
+        let mut bits = [Bit::Zero; 64];
+        let mut i = 0;
+        while i < 64 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::Zero) => Bit::Zero,
+                _ => Bit::One,
+            };
+            i += 1;
+        }
+        N64 { bits }
+    }
+
+    /// Bitwise XOR usable in const contexts.
+    pub const fn bitxor_const(self, other: N64) -> N64 {
+        let mut bits = [Bit::Zero; 64];
+        let mut i = 0;
+        while i < 64 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::One) | (Bit::One, Bit::Zero) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N64 { bits }
+    }
+
+    /// Bitwise NOT usable in const contexts.
+    pub const fn not_const(self) -> N64 {
+        let mut bits = [Bit::Zero; 64];
+        let mut i = 0;
+        while i < 64 {
+            bits[i] = match self.bits[i] {
+                Bit::Zero => Bit::One,
+                Bit::One => Bit::Zero,
+            };
+            i += 1;
+        }
+        N64 { bits }
+    }
+}
+
+impl N64 {
+    /// Two's-complement negation, `!self + 1` wrapping modulo 2^64.
+    pub fn wrapping_neg(self) -> N64 {
+        let mut bits = [Bit::Zero; 64];
+        let mut carry = Bit::One;
+        for i in 0..64 {
+            let (sum, new_carry) = Bit::half_adder(!self.bits[i], carry);
+            bits[i] = sum;
+            carry = new_carry;
+        }
+        N64 { bits }
+    }
+}
+
+impl N64 {
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: N64) -> Option<N64> {
+        u64::from(self).checked_div(u64::from(rhs)).map(N64::from)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: N64) -> Option<N64> {
+        u64::from(self).checked_rem(u64::from(rhs)).map(N64::from)
+    }
+}
+
+impl N64 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(64 + 64 / group.max(1));
+        for i in (0..64).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
+            }
+        }
+        text
+    }
+}
+
+impl N64 {
+    /// Parses a hexadecimal string such as `"0xFF"` or `"ff"`.
+    pub fn from_hex_str(s: &str) -> Result<N64, ParseBitsError> {
+        Ok(N64 {
+            bits: parse_hex_bits(s)?,
+        })
+    }
+}
+
+impl N64 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 64.
+    pub fn bit_range(&self, start: usize, len: usize) -> N64 {
+        assert!(
+            start + len <= 64,
+            "bit range {}..{} out of range for 64 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 64];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        N64 { bits }
+    }
+}
+
+impl N64 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 64 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> N64 {
+        assert!(index < 64, "bit index {} out of range for 64 bits", index);
+        self.bits[index] = bit;
+        self
+    }
+
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 64 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 64, "bit index {} out of range for 64 bits", index);
+        self.bits[index] = bit;
+    }
+
+    /// Clears the bit at `index`. Panics if `index` is 64 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 64, "bit index {} out of range for 64 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
+
+    /// Flips the bit at `index`. Panics if `index` is 64 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 64, "bit index {} out of range for 64 bits", index);
+        self.bits[index] = !self.bits[index];
+    }
+}
+
+impl N64 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: N64) -> N64 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of two values.
+    pub fn max(self, other: N64) -> N64 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: N64, hi: N64) -> N64 {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+impl Sum for N64 {
+    fn sum<I: Iterator<Item = N64>>(iter: I) -> N64 {
+        iter.fold(<N64 as KarccInt>::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a N64> for N64 {
+    fn sum<I: Iterator<Item = &'a N64>>(iter: I) -> N64 {
+        iter.copied().sum()
+    }
+}
+
+impl Product for N64 {
+    fn product<I: Iterator<Item = N64>>(iter: I) -> N64 {
+        iter.fold(<N64 as KarccInt>::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a N64> for N64 {
+    fn product<I: Iterator<Item = &'a N64>>(iter: I) -> N64 {
+        iter.copied().product()
+    }
+}
+
+impl N64 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &N64) -> Bool {
+        Bool::new(self == other)
+    }
+
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &N64) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
+
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &N64) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &N64) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &N64) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
+    }
+}
+
+impl FromIterator<Bit> for N64 {
+    fn from_iter<I: IntoIterator<Item = Bit>>(iter: I) -> Self {
+        let mut bits = [Bit::Zero; 64];
+        for (i, bit) in iter.into_iter().enumerate().take(64) {
+            bits[i] = bit;
+        }
+        N64 { bits }
+    }
+}
+
+/// Interprets the array least significant bit first: element 0 becomes bit 0.
+impl From<[bool; 64]> for N64 {
+    fn from(value: [bool; 64]) -> Self {
+        let mut bits = [Bit::Zero; 64];
+        for (i, &b) in value.iter().enumerate() {
+            if b {
+                bits[i] = Bit::One;
+            }
+        }
+        N64 { bits }
+    }
+}
+
+impl N64 {
+    /// Wrapping shift left: `n` is taken modulo 64, matching `u64::wrapping_shl`.
+    ///
+    /// `N64` has no `<<` operator; this and `shift_left_carry` are its left shifts.
+    pub fn wrapping_shl(self, n: u32) -> N64 {
+        u64::from(self).wrapping_shl(n).into()
+    }
+
+    /// Wrapping shift right: `n` is taken modulo 64, matching `u64::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> N64 {
+        u64::from(self).wrapping_shr(n).into()
+    }
+}
+
+/// Fails on targets where `usize` is narrower than 64 bits and the value does not fit.
+impl TryFrom<N64> for usize {
+    type Error = ConversionError;
+
+    fn try_from(value: N64) -> Result<Self, Self::Error> {
+        usize::try_from(u64::from(value)).map_err(|_| ConversionError)
+    }
+}
+
+/// Never fails on targets where `usize` is at most 64 bits wide.
+impl TryFrom<usize> for N64 {
+    type Error = ConversionError;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        u64::try_from(value)
+            .map(Into::into)
+            .map_err(|_| ConversionError)
+    }
+}
+
+impl N64 {
+    /// Converts to `usize` for indexing and length arithmetic.
+    ///
+    /// Panics on 32-bit targets when the value does not fit in `usize`.
+    pub fn to_usize(self) -> usize {
+        usize::try_from(self).expect("value does not fit in usize on this target")
+    }
+}
+
+impl N64 {
+    /// Returns the value as `u64`; same as `u64::from(self)`.
+    pub fn to_u64(self) -> u64 {
+        u64::from(self)
+    }
+}
+
+impl N64 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
+    }
+}
+
+// ---------------- N128 --------------------
+
+/// Unsigned 128-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct N128 {
+    bits: [Bit; 128],
+}
+
+impl From<u128> for N128 {
+    fn from(value: u128) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        for i in 0..128 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+        }
+        N128 { bits }
+    }
+}
+
+impl From<N128> for u128 {
+    fn from(value: N128) -> Self {
+        value
+            .bits
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, &bit)| acc | (u8::from(bit) as u128) << i)
+    }
+}
+
+impl Add for N128 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        u128::from(self).wrapping_add(u128::from(other)).into()
+    }
+}
+
+impl Sub for N128 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        u128::from(self).wrapping_sub(u128::from(other)).into()
+    }
+}
+
+impl Mul for N128 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        u128::from(self).wrapping_mul(u128::from(other)).into()
+    }
+}
+
+impl Div for N128 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        (u128::from(self) / u128::from(other)).into()
+    }
+}
+
+impl AddAssign for N128 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for N128 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl BitCount for N128 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        128 - self.count_ones()
+    }
+}
+
+impl Display for N128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u128::from(*self))
+    }
+}
+
+/// Shows the decimal value followed by the bits, MSB first.
+impl fmt::Debug for N128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = u128::from(*self);
+        write!(f, "N128({} = {:#0130b})", value, value)
+    }
+}
+
+impl N128 {
+    /// Addition returning the wrapped sum and whether an overflow occurred.
+    pub fn overflowing_add(self, other: N128) -> (N128, Bool) {
+        let (result, overflow) = u128::from(self).overflowing_add(u128::from(other));
+        (result.into(), Bool::new(overflow))
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, other: N128) -> Option<N128> {
+        u128::from(self)
+            .checked_add(u128::from(other))
+            .map(N128::from)
+    }
+
+    /// Checked subtraction. Returns `None` on underflow.
+    pub fn checked_sub(self, other: N128) -> Option<N128> {
+        u128::from(self)
+            .checked_sub(u128::from(other))
+            .map(N128::from)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, other: N128) -> Option<N128> {
+        u128::from(self)
+            .checked_mul(u128::from(other))
+            .map(N128::from)
+    }
+}
+
+impl N128 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: N128) -> N128 {
+        u128::from(self).saturating_add(u128::from(other)).into()
+    }
+
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: N128) -> N128 {
+        u128::from(self).saturating_sub(u128::from(other)).into()
+    }
+}
+
+impl BitwiseReverse for N128 {
+    fn reverse_bits(&mut self) {
+        self.bits.reverse();
+    }
+}
+
+impl BitwiseRotate for N128 {
+    fn rotate_left(&mut self, n: u32) {
+        let val = u128::from(*self);
+        let rotated = val.rotate_left(n);
+        *self = rotated.into();
+    }
+    fn rotate_right(&mut self, n: u32) {
+        let val = u128::from(*self);
+        let rotated = val.rotate_right(n);
+        *self = rotated.into();
+    }
+}
+
+impl fmt::LowerHex for N128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&u128::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for N128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&u128::from(*self), f)
+    }
+}
+
+impl fmt::Binary for N128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&u128::from(*self), f)
+    }
+}
+
+impl fmt::Octal for N128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&u128::from(*self), f)
+    }
+}
+
+/// Parses an MSB-first binary digit string, matching `N8`'s convention.
+impl FromStr for N128 {
+    type Err = ParseBitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(N128 {
+            bits: parse_bits(s)?,
+        })
+    }
+}
+
+impl From<N8> for N128 {
+    fn from(value: N8) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        bits[..8].copy_from_slice(&value.bits);
+        N128 { bits }
+    }
+}
+
+impl From<N16> for N128 {
+    fn from(value: N16) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        bits[..16].copy_from_slice(&value.bits);
+        N128 { bits }
+    }
+}
+
+impl From<N32> for N128 {
+    fn from(value: N32) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        bits[..32].copy_from_slice(&value.bits);
+        N128 { bits }
+    }
+}
+
+impl From<N64> for N128 {
+    fn from(value: N64) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        bits[..64].copy_from_slice(&value.bits);
+        N128 { bits }
+    }
+}
+
+impl TryFrom<N128> for N64 {
+    type Error = ConversionError;
+
+    fn try_from(value: N128) -> Result<Self, Self::Error> {
+        if value.bits[64..].contains(&Bit::One) {
+            return Err(ConversionError);
+        }
+        let mut bits = [Bit::Zero; 64];
+        bits.copy_from_slice(&value.bits[..64]);
+        Ok(N64 { bits })
+    }
+}
+
+impl BitScan for N128 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
+    }
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl N128 {
+    /// Returns `Bool::True` if exactly one bit is set. Zero is not a power of two.
+    pub fn is_power_of_two(&self) -> Bool {
+        Bool::new(self.count_ones() == 1)
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// Zero maps to one. If the result does not fit, it wraps to zero, which is
+    /// what the primitive `next_power_of_two` does in release builds.
+    pub fn next_power_of_two(self) -> N128 {
+        u128::from(self)
+            .checked_next_power_of_two()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+impl N128 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> N128 {
+        let mut result = N128::from(1);
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl PartialOrd for N128 {
+    fn partial_cmp(&self, other: &N128) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for N128 {
+    fn cmp(&self, other: &N128) -> Ordering {
+        u128::from(*self).cmp(&u128::from(*other))
+    }
+}
+
+impl Not for N128 {
+    type Output = N128;
+
+    fn not(self) -> Self::Output {
+        N128 {
+            bits: self.bits.map(|bit| !bit),
+        }
+    }
+}
+
+impl core::ops::BitAnd for N128 {
+    type Output = N128;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 128];
+        for i in 0..128 {
+            bits[i] = self.bits[i] & other.bits[i];
+        }
+        N128 { bits }
+    }
+}
+
+impl core::ops::BitOr for N128 {
+    type Output = N128;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 128];
+        for i in 0..128 {
+            bits[i] = self.bits[i] | other.bits[i];
+        }
+        N128 { bits }
+    }
+}
+
+impl core::ops::BitXor for N128 {
+    type Output = N128;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        let mut bits = [Bit::Zero; 128];
+        for i in 0..128 {
+            bits[i] = self.bits[i] ^ other.bits[i];
+        }
+        N128 { bits }
+    }
+}
+
+impl core::ops::BitAndAssign for N128 {
+    fn bitand_assign(&mut self, other: Self) {
+        for i in 0..128 {
+            self.bits[i] &= other.bits[i];
+        }
+    }
+}
+
+impl core::ops::BitOrAssign for N128 {
+    fn bitor_assign(&mut self, other: Self) {
+        for i in 0..128 {
+            self.bits[i] |= other.bits[i];
+        }
+    }
+}
+
+impl core::ops::BitXorAssign for N128 {
+    fn bitxor_assign(&mut self, other: Self) {
+        for i in 0..128 {
+            self.bits[i] ^= other.bits[i];
+        }
+    }
+}
+
+impl Rem for N128 {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        (u128::from(self) % u128::from(other)).into()
+    }
+}
+
+impl MulAssign for N128 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for N128 {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl RemAssign for N128 {
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
+
+impl Index<usize> for N128 {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl N128 {
+    /// Returns an iterator over the bits, least significant bit first.
+    pub fn iter_bits(&self) -> core::array::IntoIter<Bit, 128> {
+        self.bits.into_iter()
+    }
+
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = u128::from(*self);
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
+    }
+}
+
+impl IntoIterator for N128 {
+    type Item = Bit;
+    type IntoIter = core::array::IntoIter<Bit, 128>;
+
+    /// Yields the bits least significant first, matching `FromIterator`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits.into_iter()
+    }
+}
+
+impl N128 {
+    /// Shifts left by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 128 (`u128::wrapping_shl`), so
+    /// a shift by 128 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_left_carry(self, n: u32) -> (N128, Bit) {
+        let n = (n % 128) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 128];
+        bits[n..].copy_from_slice(&self.bits[..128 - n]);
+        (N128 { bits }, self.bits[128 - n])
+    }
+
+    /// Logical right shift by `n`, returning the result and the last bit shifted out.
+    ///
+    /// Like the primitive shift, `n` is taken modulo 128 (`u128::wrapping_shr`), so
+    /// a shift by 128 leaves `self` unchanged. Shifting by zero reports `Bit::Zero`.
+    pub fn shift_right_carry(self, n: u32) -> (N128, Bit) {
+        let n = (n % 128) as usize;
+        if n == 0 {
+            return (self, Bit::Zero);
+        }
+        let mut bits = [Bit::Zero; 128];
+        bits[..128 - n].copy_from_slice(&self.bits[n..]);
+        (N128 { bits }, self.bits[n - 1])
+    }
+}
+
+impl KarccInt for N128 {
+    type Primitive = u128;
+
+    const ZERO: Self = N128::from_u128(0);
+    const ONE: Self = N128::from_u128(1);
+    const MIN: Self = N128::from_u128(u128::MIN);
+    const MAX: Self = N128::from_u128(u128::MAX);
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        N128::checked_add(self, rhs)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        N128::checked_sub(self, rhs)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        N128::checked_mul(self, rhs)
+    }
+}
+
+impl N128 {
+    /// Returns the value as bytes in little-endian order, as `u128::to_le_bytes`.
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        u128::from(self).to_le_bytes()
+    }
+
+    /// Returns the value as bytes in big-endian order, as `u128::to_be_bytes`.
+    pub fn to_be_bytes(self) -> [u8; 16] {
+        u128::from(self).to_be_bytes()
+    }
+
+    /// Builds a value from little-endian bytes, as `u128::from_le_bytes`.
+    pub fn from_le_bytes(bytes: [u8; 16]) -> N128 {
+        u128::from_le_bytes(bytes).into()
+    }
+
+    /// Builds a value from big-endian bytes, as `u128::from_be_bytes`.
+    pub fn from_be_bytes(bytes: [u8; 16]) -> N128 {
+        u128::from_be_bytes(bytes).into()
+    }
+}
+
+impl N128 {
+    /// Reverses the order of the bytes, keeping the bits within each byte in place.
+    pub fn swap_bytes(self) -> N128 {
+        let mut bits = [Bit::Zero; 128];
+        for (i, byte) in self.bits.chunks(8).enumerate() {
+            let start = 128 - 8 * (i + 1);
+            bits[start..start + 8].copy_from_slice(byte);
+        }
+        N128 { bits }
+    }
+}
+
+impl N128 {
+    /// Encodes the value as reflected binary Gray code, `x ^ (x >> 1)`.
+    pub fn to_gray(self) -> N128 {
+        self ^ (self.shift_right_carry(1).0)
+    }
+
+    /// Decodes a Gray code value, the inverse of `to_gray`.
+    pub fn from_gray(self) -> N128 {
+        let mut value = self;
+        let mut shifted = self.shift_right_carry(1).0;
+        while shifted != N128::ZERO {
+            value ^= shifted;
+            shifted = shifted.shift_right_carry(1).0;
+        }
+        value
+    }
+}
+
+impl Parity for N128 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
+
+impl N128 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &N128) -> u32 {
+        (*self ^ *other).count_ones()
+    }
+}
+
+impl N128 {
+    /// Creates an `N128` from a `u128` in a const context.
+    pub const fn from_u128(value: u128) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        let mut i = 0;
+        while i < 128 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+            i += 1;
+        }
+        N128 { bits }
+    }
+
+    /// Bitwise AND usable in const contexts.
+    pub const fn bitand_const(self, other: N128) -> N128 {
+        let mut bits = [Bit::Zero; 128];
+        let mut i = 0;
+        while i < 128 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::One, Bit::One) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N128 { bits }
+    }
+
+    /// Bitwise OR usable in const contexts.
+    pub const fn bitor_const(self, other: N128) -> N128 {
+        let mut bits = [Bit::Zero; 128];
+        let mut i = 0;
+        while i < 128 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::Zero) => Bit::Zero,
+                _ => Bit::One,
+            };
+            i += 1;
+        }
+        N128 { bits }
+    }
+
+    /// Bitwise XOR usable in const contexts.
+    pub const fn bitxor_const(self, other: N128) -> N128 {
+        let mut bits = [Bit::Zero; 128];
+        let mut i = 0;
+        while i < 128 {
+            bits[i] = match (self.bits[i], other.bits[i]) {
+                (Bit::Zero, Bit::One) | (Bit::One, Bit::Zero) => Bit::One,
+                _ => Bit::Zero,
+            };
+            i += 1;
+        }
+        N128 { bits }
+    }
+
+    /// Bitwise NOT usable in const contexts.
+    pub const fn not_const(self) -> N128 {
+        let mut bits = [Bit::Zero; 128];
+        let mut i = 0;
+        while i < 128 {
+            bits[i] = match self.bits[i] {
+                Bit::Zero => Bit::One,
+                Bit::One => Bit::Zero,
+            };
+            i += 1;
+        }
+        N128 { bits }
+    }
+}
+
+impl N128 {
+    /// Two's-complement negation, `!self + 1` wrapping modulo 2^128.
+    pub fn wrapping_neg(self) -> N128 {
+        let mut bits = [Bit::Zero; 128];
+        let mut carry = Bit::One;
+        for i in 0..128 {
+            let (sum, new_carry) = Bit::half_adder(!self.bits[i], carry);
+            bits[i] = sum;
+            carry = new_carry;
+        }
+        N128 { bits }
+    }
+}
+
+impl N128 {
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: N128) -> Option<N128> {
+        u128::from(self)
+            .checked_div(u128::from(rhs))
+            .map(N128::from)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: N128) -> Option<N128> {
+        u128::from(self)
+            .checked_rem(u128::from(rhs))
+            .map(N128::from)
+    }
+}
+
+impl N128 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(128 + 128 / group.max(1));
+        for i in (0..128).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
+            }
+        }
+        text
+    }
+}
+
+impl N128 {
+    /// Parses a hexadecimal string such as `"0xFF"` or `"ff"`.
+    pub fn from_hex_str(s: &str) -> Result<N128, ParseBitsError> {
+        Ok(N128 {
+            bits: parse_hex_bits(s)?,
+        })
+    }
+}
+
+impl N128 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 128.
+    pub fn bit_range(&self, start: usize, len: usize) -> N128 {
+        assert!(
+            start + len <= 128,
+            "bit range {}..{} out of range for 128 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 128];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        N128 { bits }
+    }
+}
+
+impl N128 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 128 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> N128 {
+        assert!(index < 128, "bit index {} out of range for 128 bits", index);
+        self.bits[index] = bit;
+        self
+    }
+
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 128 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 128, "bit index {} out of range for 128 bits", index);
+        self.bits[index] = bit;
+    }
+
+    /// Clears the bit at `index`. Panics if `index` is 128 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 128, "bit index {} out of range for 128 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
+
+    /// Flips the bit at `index`. Panics if `index` is 128 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 128, "bit index {} out of range for 128 bits", index);
+        self.bits[index] = !self.bits[index];
+    }
+}
+
+impl N128 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: N128) -> N128 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of two values.
+    pub fn max(self, other: N128) -> N128 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: N128, hi: N128) -> N128 {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+impl Sum for N128 {
+    fn sum<I: Iterator<Item = N128>>(iter: I) -> N128 {
+        iter.fold(<N128 as KarccInt>::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a N128> for N128 {
+    fn sum<I: Iterator<Item = &'a N128>>(iter: I) -> N128 {
+        iter.copied().sum()
+    }
+}
+
+impl Product for N128 {
+    fn product<I: Iterator<Item = N128>>(iter: I) -> N128 {
+        iter.fold(<N128 as KarccInt>::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a N128> for N128 {
+    fn product<I: Iterator<Item = &'a N128>>(iter: I) -> N128 {
+        iter.copied().product()
+    }
+}
+
+impl N128 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &N128) -> Bool {
+        Bool::new(self == other)
+    }
+
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &N128) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
+
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &N128) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &N128) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &N128) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
+    }
+}
+
+impl FromIterator<Bit> for N128 {
+    fn from_iter<I: IntoIterator<Item = Bit>>(iter: I) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        for (i, bit) in iter.into_iter().enumerate().take(128) {
+            bits[i] = bit;
+        }
+        N128 { bits }
+    }
+}
+
+/// Interprets the array least significant bit first: element 0 becomes bit 0.
+impl From<[bool; 128]> for N128 {
+    fn from(value: [bool; 128]) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        for (i, &b) in value.iter().enumerate() {
+            if b {
+                bits[i] = Bit::One;
+            }
+        }
+        N128 { bits }
+    }
+}
+
+impl N128 {
+    /// Wrapping shift left: `n` is taken modulo 128, matching `u128::wrapping_shl`.
+    ///
+    /// `N128` has no `<<` operator; this and `shift_left_carry` are its left shifts.
+    pub fn wrapping_shl(self, n: u32) -> N128 {
+        u128::from(self).wrapping_shl(n).into()
+    }
+
+    /// Wrapping shift right: `n` is taken modulo 128, matching `u128::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> N128 {
+        u128::from(self).wrapping_shr(n).into()
+    }
+}
+
+impl N128 {
+    /// Returns the value as `u128`; same as `u128::from(self)`.
+    pub fn to_u128(self) -> u128 {
+        u128::from(self)
+    }
+}
+
+impl N128 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
+    }
+}
+
+// ---------------- Nuint --------------------
+
+/// Unsigned integer of arbitrary width `BITS`.
+///
+/// Unlike the fixed-width `N` types, every operation here is carried out on the
+/// bit array itself, so odd widths such as `Nuint<12>` or `Nuint<24>` wrap
+/// modulo `2^BITS` exactly like hardware registers of that size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Nuint<const BITS: usize> {
+    bits: [Bit; BITS],
+}
+
+impl<const BITS: usize> Nuint<BITS> {
+    /// Zero value.
+    pub const ZERO: Self = Nuint {
+        bits: [Bit::Zero; BITS],
+    };
+
+    /// Maximum value (2^BITS - 1).
+    pub const MAX: Self = Nuint {
+        bits: [Bit::One; BITS],
+    };
+
+    /// Creates a new `Nuint` from an array of bits, least significant first.
+    pub fn new(bits: [Bit; BITS]) -> Self {
+        Nuint { bits }
+    }
+
+    /// Returns the underlying bits, least significant first.
+    pub fn bits(&self) -> [Bit; BITS] {
+        self.bits
+    }
+
+    /// Adds `other`, returning the wrapped sum and the carry out of the top bit.
+    pub fn overflowing_add(self, other: Self) -> (Self, Bool) {
+        let mut carry = Bit::Zero;
+        let mut bits = [Bit::Zero; BITS];
+        for i in 0..BITS {
+            let (sum, new_carry) = full_adder(self.bits[i], other.bits[i], carry);
+            bits[i] = sum;
+            carry = new_carry;
+        }
+        (Nuint { bits }, carry.as_bool())
+    }
+
+    /// Subtracts `other`, returning the wrapped difference and the borrow out of the top bit.
+    pub fn overflowing_sub(self, other: Self) -> (Self, Bool) {
+        let mut borrow = Bit::Zero;
+        let mut bits = [Bit::Zero; BITS];
+        for i in 0..BITS {
+            let (diff, new_borrow) = full_subtractor(self.bits[i], other.bits[i], borrow);
+            bits[i] = diff;
+            borrow = new_borrow;
+        }
+        (Nuint { bits }, borrow.as_bool())
+    }
+
+    /// Restoring division over the bit arrays, returning `(quotient, remainder)`.
+    ///
+    /// The bit shifted out of the remainder register is kept in `top`, standing
+    /// in for the extra register bit that `N8::div_rem` allocates explicitly.
+    fn div_rem(self, divisor: Self) -> (Self, Self) {
+        if divisor == Self::ZERO {
+            panic!("attempt to divide by zero");
+        }
+        let mut quotient = [Bit::Zero; BITS];
+        let mut remainder = Self::ZERO;
+        for i in (0..BITS).rev() {
+            let top = remainder.bits[BITS - 1];
+            remainder.bits.copy_within(0..BITS - 1, 1);
+            remainder.bits[0] = self.bits[i];
+
+            let (trial, borrow) = remainder.overflowing_sub(divisor);
+            if top == Bit::One || borrow == Bool::False {
+                remainder = trial;
+                quotient[i] = Bit::One;
+            }
+        }
+        (Nuint { bits: quotient }, remainder)
+    }
+}
+
+impl<const BITS: usize> Default for Nuint<BITS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const BITS: usize> From<u128> for Nuint<BITS> {
+    /// Keeps the low `BITS` bits of `value`.
+    fn from(value: u128) -> Self {
+        let mut bits = [Bit::Zero; BITS];
+        for i in 0..BITS.min(128) {
+            bits[i] = if (value >> i) & 1 == 1 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+        }
+        Nuint { bits }
+    }
+}
+
+impl<const BITS: usize> TryFrom<Nuint<BITS>> for u128 {
+    type Error = ConversionError;
+
+    fn try_from(value: Nuint<BITS>) -> Result<Self, Self::Error> {
+        if BITS > 128 && value.bits[128..].contains(&Bit::One) {
+            return Err(ConversionError);
+        }
+        let mut result = 0u128;
+        for i in 0..BITS.min(128) {
+            if value.bits[i] == Bit::One {
+                result |= 1 << i;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl From<N8> for Nuint<8> {
+    fn from(value: N8) -> Self {
+        Nuint { bits: value.bits }
+    }
+}
+
+impl From<Nuint<8>> for N8 {
+    fn from(value: Nuint<8>) -> Self {
+        N8 { bits: value.bits }
+    }
+}
+
+impl From<N16> for Nuint<16> {
+    fn from(value: N16) -> Self {
+        Nuint { bits: value.bits }
+    }
+}
+
+impl From<Nuint<16>> for N16 {
+    fn from(value: Nuint<16>) -> Self {
+        N16 { bits: value.bits }
+    }
+}
+
+impl From<N32> for Nuint<32> {
+    fn from(value: N32) -> Self {
+        Nuint { bits: value.bits }
+    }
+}
+
+impl From<Nuint<32>> for N32 {
+    fn from(value: Nuint<32>) -> Self {
+        N32 { bits: value.bits }
+    }
+}
+
+impl From<N64> for Nuint<64> {
+    fn from(value: N64) -> Self {
+        Nuint { bits: value.bits }
+    }
+}
+
+impl From<Nuint<64>> for N64 {
+    fn from(value: Nuint<64>) -> Self {
+        N64 { bits: value.bits }
+    }
+}
+
+impl From<N128> for Nuint<128> {
+    fn from(value: N128) -> Self {
+        Nuint { bits: value.bits }
+    }
+}
+
+impl From<Nuint<128>> for N128 {
+    fn from(value: Nuint<128>) -> Self {
+        N128 { bits: value.bits }
+    }
+}
+
+impl<const BITS: usize> Add for Nuint<BITS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.overflowing_add(other).0
+    }
+}
+
+impl<const BITS: usize> Sub for Nuint<BITS> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+}
+
+impl<const BITS: usize> Mul for Nuint<BITS> {
+    type Output = Self;
+
+    /// Shift-and-add multiplication, wrapping modulo 2^BITS like `Add`.
+    fn mul(self, other: Self) -> Self {
+        let mut product = Self::ZERO;
+        for shift in 0..BITS {
+            if other.bits[shift] == Bit::One {
+                let mut partial = [Bit::Zero; BITS];
+                partial[shift..].copy_from_slice(&self.bits[..BITS - shift]);
+                product += Nuint { bits: partial };
+            }
+        }
+        product
+    }
+}
+
+impl<const BITS: usize> Div for Nuint<BITS> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.div_rem(other).0
+    }
+}
+
+impl<const BITS: usize> Rem for Nuint<BITS> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(other).1
+    }
+}
+
+impl<const BITS: usize> AddAssign for Nuint<BITS> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const BITS: usize> SubAssign for Nuint<BITS> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const BITS: usize> MulAssign for Nuint<BITS> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const BITS: usize> DivAssign for Nuint<BITS> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<const BITS: usize> RemAssign for Nuint<BITS> {
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
+
+impl<const BITS: usize> Not for Nuint<BITS> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Nuint {
+            bits: self.bits.map(|bit| !bit),
+        }
+    }
+}
+
+impl<const BITS: usize> core::ops::BitAnd for Nuint<BITS> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        let mut bits = self.bits;
+        for i in 0..BITS {
+            bits[i] &= other.bits[i];
+        }
+        Nuint { bits }
+    }
+}
+
+impl<const BITS: usize> core::ops::BitOr for Nuint<BITS> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        let mut bits = self.bits;
+        for i in 0..BITS {
+            bits[i] |= other.bits[i];
+        }
+        Nuint { bits }
+    }
+}
+
+impl<const BITS: usize> core::ops::BitXor for Nuint<BITS> {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        let mut bits = self.bits;
+        for i in 0..BITS {
+            bits[i] ^= other.bits[i];
+        }
+        Nuint { bits }
+    }
+}
+
+/// Takes a `u32` shift amount rather than the `u8` used by the fixed-width types,
+/// because `BITS` may exceed 255 and every in-range shift must be expressible.
+impl<const BITS: usize> Shl<u32> for Nuint<BITS> {
+    type Output = Self;
+
+    /// Shifts towards the most significant bit; shifting by `BITS` or more yields zero.
+    fn shl(self, shift: u32) -> Self::Output {
+        let mut result = Self::ZERO;
+        let shift = shift as usize;
+        if shift >= BITS {
+            return result;
+        }
+        result.bits[shift..].copy_from_slice(&self.bits[..BITS - shift]);
+        result
+    }
+}
+
+/// Takes a `u32` shift amount for the same reason as `Shl`.
+impl<const BITS: usize> Shr<u32> for Nuint<BITS> {
+    type Output = Self;
+
+    /// Shifts towards the least significant bit; shifting by `BITS` or more yields zero.
+    fn shr(self, shift: u32) -> Self::Output {
+        let mut result = Self::ZERO;
+        let shift = shift as usize;
+        if shift >= BITS {
+            return result;
+        }
+        result.bits[..BITS - shift].copy_from_slice(&self.bits[shift..]);
+        result
+    }
+}
+
+impl<const BITS: usize> PartialOrd for Nuint<BITS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BITS: usize> Ord for Nuint<BITS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for i in (0..BITS).rev() {
+            match (self.bits[i], other.bits[i]) {
+                (Bit::One, Bit::Zero) => return Ordering::Greater,
+                (Bit::Zero, Bit::One) => return Ordering::Less,
+                _ => {}
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl<const BITS: usize> BitCount for Nuint<BITS> {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::Zero).count() as u32
+    }
+}
+
+impl<const BITS: usize> Parity for Nuint<BITS> {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const BITS: usize> Display for Nuint<BITS> {
+    /// Formats the value in decimal by doubling a base-10 digit buffer per bit.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut digits = vec![0u8];
+        for i in (0..BITS).rev() {
+            let mut carry = u8::from(self.bits[i] == Bit::One);
+            for digit in digits.iter_mut() {
+                let doubled = *digit * 2 + carry;
+                *digit = doubled % 10;
+                carry = doubled / 10;
+            }
+            if carry > 0 {
+                digits.push(carry);
+            }
+        }
+        let text: String = digits.iter().rev().map(|d| (b'0' + d) as char).collect();
+        f.pad_integral(true, "", &text)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const BITS: usize> fmt::Binary for Nuint<BITS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let text: String = match self.bits.iter().rposition(|&bit| bit == Bit::One) {
+            Some(top) => self.bits[..=top]
+                .iter()
+                .rev()
+                .map(|&bit| if bit == Bit::One { '1' } else { '0' })
+                .collect(),
+            None => String::from("0"),
+        };
+        f.pad_integral(true, "0b", &text)
+    }
+}
+
+impl<const BITS: usize> Index<usize> for Nuint<BITS> {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+// ---------------- ALU --------------------
+
+/// Operation selected by an [`Alu`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AluOp {
+    Add,
+    Sub,
+    And,
+    Or,
+    Xor,
+    /// Bitwise NOT of the first operand; the second operand is ignored.
+    Not,
+}
+
+/// Status flags produced by an [`Alu`] operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Flags {
+    /// Set when the result is zero.
+    pub zero: Bool,
+    /// Carry out of the top bit for `Add`, borrow out of the top bit for `Sub`.
+    pub carry: Bool,
+    /// Copy of the result's top bit.
+    pub negative: Bool,
+    /// Set when the result overflows as a signed two's-complement value.
+    pub overflow: Bool,
+}
+
+/// Output of an [`Alu`] operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AluResult {
+    pub value: N8,
+    pub flags: Flags,
+}
+
+/// 8-bit arithmetic logic unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Alu;
+
+impl Alu {
+    /// Performs `op` on `a` and `b`, returning the result and status flags.
+    ///
+    /// Carry and overflow come straight from the adder/subtractor chain: carry is
+    /// the bit leaving position 7 and overflow is set when it differs from the bit
+    /// entering position 7. Logic operations clear both.
+    pub fn execute(op: AluOp, a: N8, b: N8) -> AluResult {
+        let mut bits = [Bit::Zero; 8];
+        let mut carry = Bit::Zero;
+        let mut overflow = Bit::Zero;
+        match op {
+            AluOp::Add | AluOp::Sub => {
+                for i in 0..8 {
+                    let (bit, carry_out) = if op == AluOp::Add {
+                        full_adder(a.bits[i], b.bits[i], carry)
+                    } else {
+                        full_subtractor(a.bits[i], b.bits[i], carry)
+                    };
+                    if i == 7 {
+                        overflow = carry ^ carry_out;
+                    }
+                    bits[i] = bit;
+                    carry = carry_out;
+                }
+            }
+            AluOp::And | AluOp::Or | AluOp::Xor | AluOp::Not => {
+                for i in 0..8 {
+                    bits[i] = match op {
+                        AluOp::And => a.bits[i] & b.bits[i],
+                        AluOp::Or => a.bits[i] | b.bits[i],
+                        AluOp::Xor => a.bits[i] ^ b.bits[i],
+                        _ => !a.bits[i],
+                    };
+                }
+            }
+        }
+        let zero = if bits.contains(&Bit::One) {
+            Bool::False
+        } else {
+            Bool::True
+        };
+        AluResult {
+            value: N8 { bits },
+            flags: Flags {
+                zero,
+                carry: carry.as_bool(),
+                negative: bits[7].as_bool(),
+                overflow: overflow.as_bool(),
+            },
+        }
+    }
+}
+
+// --------------------- Z8 ---------------------
+
+/// Signed 8-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Z8 {
+    bits: [Bit; 8],
+}
+
+impl Z8 {
+    /// Minimum value for Z8 (-128).
+    pub const MIN: Z8 = {
+        let mut bits = [Bit::Zero; 8];
+        bits[7] = Bit::One;
+        Z8 { bits }
+    };
+
+    /// Maximum value for Z8 (127).
+    pub const MAX: Z8 = {
+        let mut bits = [Bit::One; 8];
+        bits[7] = Bit::Zero;
+        Z8 { bits }
+    };
+}
+
+impl From<i8> for Z8 {
+    fn from(value: i8) -> Self {
+        let mut bits = [Bit::Zero; 8];
+        for i in 0..8 {
+            if (value & (1 << i)) != 0 {
+                bits[i] = Bit::One;
+            }
+        }
+        Z8 { bits }
+    }
+}
+
+impl From<Z8> for i8 {
+    fn from(value: Z8) -> Self {
+        let mut result: i8 = 0;
+        for i in 0..8 {
+            if value.bits[i] == Bit::One {
+                result |= 1 << i;
+            }
+        }
+        result
+    }
+}
+
+impl Add for Z8 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        i8::from(self).wrapping_add(i8::from(rhs)).into()
+    }
+}
+
+impl Sub for Z8 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        i8::from(self).wrapping_sub(i8::from(rhs)).into()
+    }
+}
+
+impl Mul for Z8 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        i8::from(self).wrapping_mul(i8::from(rhs)).into()
+    }
+}
+
+impl Div for Z8 {
+    type Output = Self;
+    /// Truncating division. Panics if `rhs` is zero; `Z8::MIN / -1` wraps to
+    /// `Z8::MIN`. Use `checked_div` to handle both cases without panicking.
+    fn div(self, rhs: Self) -> Self::Output {
+        i8::from(self).wrapping_div(i8::from(rhs)).into()
+    }
+}
+
+impl Rem for Z8 {
+    type Output = Self;
+    /// Truncating remainder. Panics if `rhs` is zero; `Z8::MIN % -1` is zero.
+    /// Use `checked_rem` to handle both cases without panicking.
+    fn rem(self, rhs: Self) -> Self::Output {
+        i8::from(self).wrapping_rem(i8::from(rhs)).into()
+    }
+}
+
+impl Not for Z8 {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        (!i8::from(self)).into()
+    }
+}
+
+impl Neg for Z8 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        i8::from(self).wrapping_neg().into()
+    }
+}
+
+impl Display for Z8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", i8::from(*self))
+    }
+}
+
+/// Shows the decimal value followed by the two's complement bits, MSB first.
+impl fmt::Debug for Z8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = i8::from(*self);
+        write!(f, "Z8({} = {:#010b})", value, value)
+    }
+}
+
+impl Z8 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: Z8) -> Z8 {
+        i8::from(self).saturating_add(i8::from(other)).into()
+    }
+
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: Z8) -> Z8 {
+        i8::from(self).saturating_sub(i8::from(other)).into()
+    }
+}
+
+impl fmt::LowerHex for Z8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&i8::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for Z8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&i8::from(*self), f)
+    }
+}
+
+impl BitScan for Z8 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
+    }
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl Z8 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> Z8 {
+        let mut result = Z8::from(1);
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl Z8 {
+    /// Returns the absolute value. `Z8::MIN.abs()` has no positive
+    /// counterpart and wraps back to `Z8::MIN`, like `i8::wrapping_abs`.
+    pub fn abs(self) -> Z8 {
+        i8::from(self).wrapping_abs().into()
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of the value.
+    pub fn signum(self) -> Z8 {
+        i8::from(self).signum().into()
+    }
+}
+
+impl PartialOrd for Z8 {
+    fn partial_cmp(&self, other: &Z8) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Z8 {
+    fn cmp(&self, other: &Z8) -> Ordering {
+        i8::from(*self).cmp(&i8::from(*other))
+    }
+}
+
+impl core::ops::BitAnd for Z8 {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        (i8::from(self) & i8::from(rhs)).into()
+    }
+}
+
+impl core::ops::BitOr for Z8 {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        (i8::from(self) | i8::from(rhs)).into()
+    }
+}
+
+impl core::ops::BitXor for Z8 {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (i8::from(self) ^ i8::from(rhs)).into()
+    }
+}
+
+/// Shifts along the bit array; shifting by 8 or more yields zero.
+impl Shl<u8> for Z8 {
+    type Output = Self;
+    fn shl(self, rhs: u8) -> Self::Output {
+        let mut bits = [Bit::Zero; 8];
+        let shift = rhs as usize;
+        if shift >= 8 {
+            return Z8 { bits };
+        }
+        bits[shift..].copy_from_slice(&self.bits[..8 - shift]);
+        Z8 { bits }
+    }
+}
+
+/// Arithmetic shift: the sign bit is replicated into the vacated positions.
+/// Shifting by 8 or more fills every bit with the sign bit.
+impl Shr<u8> for Z8 {
+    type Output = Self;
+    fn shr(self, rhs: u8) -> Self::Output {
+        let sign = self.bits[7];
+        let mut bits = [sign; 8];
+        let shift = rhs as usize;
+        if shift >= 8 {
+            return Z8 { bits };
+        }
+        bits[..8 - shift].copy_from_slice(&self.bits[shift..]);
+        Z8 { bits }
+    }
+}
+
+impl ShlAssign<u8> for Z8 {
+    fn shl_assign(&mut self, shift: u8) {
+        *self = *self << shift;
+    }
+}
+
+impl ShrAssign<u8> for Z8 {
+    fn shr_assign(&mut self, shift: u8) {
+        *self = *self >> shift;
+    }
+}
+
+impl AddAssign for Z8 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Z8 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Z8 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Index<usize> for Z8 {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl KarccInt for Z8 {
+    type Primitive = i8;
+
+    const ZERO: Self = Z8::from_i8(0);
+    const ONE: Self = Z8::from_i8(1);
+    const MIN: Self = Z8::MIN;
+    const MAX: Self = Z8::MAX;
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i8::from(self).checked_add(i8::from(rhs)).map(Z8::from)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i8::from(self).checked_sub(i8::from(rhs)).map(Z8::from)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i8::from(self).checked_mul(i8::from(rhs)).map(Z8::from)
+    }
+}
+
+impl Parity for Z8 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
+
+impl Z8 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &Z8) -> u32 {
+        (*self ^ *other).count_ones()
+    }
+}
+
+impl Z8 {
+    /// Creates a `Z8` from an `i8` in a const context.
+    pub const fn from_i8(value: i8) -> Self {
+        let mut bits = [Bit::Zero; 8];
+        let mut i = 0;
+        while i < 8 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+            i += 1;
+        }
+        Z8 { bits }
+    }
+}
+
+impl BitCount for Z8 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        8 - self.count_ones()
+    }
+}
+
+impl Z8 {
+    /// Euclidean division, rounding so that `rem_euclid` is never negative.
+    /// Wraps for `MIN / -1` like `/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_euclid(self, rhs: Z8) -> Z8 {
+        i8::from(self).wrapping_div_euclid(i8::from(rhs)).into()
+    }
+
+    /// Euclidean remainder, always in `0..rhs.abs()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn rem_euclid(self, rhs: Z8) -> Z8 {
+        i8::from(self).wrapping_rem_euclid(i8::from(rhs)).into()
+    }
+}
+
+impl Z8 {
+    /// Checked division. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_div(self, rhs: Z8) -> Option<Z8> {
+        i8::from(self).checked_div(i8::from(rhs)).map(Z8::from)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_rem(self, rhs: Z8) -> Option<Z8> {
+        i8::from(self).checked_rem(i8::from(rhs)).map(Z8::from)
+    }
+}
+
+impl Z8 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(8 + 8 / group.max(1));
+        for i in (0..8).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
+            }
+        }
+        text
+    }
+}
+
+impl Z8 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 8.
+    pub fn bit_range(&self, start: usize, len: usize) -> Z8 {
+        assert!(
+            start + len <= 8,
+            "bit range {}..{} out of range for 8 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 8];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        Z8 { bits }
+    }
+}
+
+impl Z8 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 8 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> Z8 {
+        assert!(index < 8, "bit index {} out of range for 8 bits", index);
+        self.bits[index] = bit;
+        self
+    }
+
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 8 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 8, "bit index {} out of range for 8 bits", index);
+        self.bits[index] = bit;
+    }
+
+    /// Clears the bit at `index`. Panics if `index` is 8 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 8, "bit index {} out of range for 8 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
+
+    /// Flips the bit at `index`. Panics if `index` is 8 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 8, "bit index {} out of range for 8 bits", index);
+        self.bits[index] = !self.bits[index];
+    }
+}
+
+impl Z8 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: Z8) -> Z8 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of two values.
+    pub fn max(self, other: Z8) -> Z8 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: Z8, hi: Z8) -> Z8 {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+impl Sum for Z8 {
+    fn sum<I: Iterator<Item = Z8>>(iter: I) -> Z8 {
+        iter.fold(<Z8 as KarccInt>::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Z8> for Z8 {
+    fn sum<I: Iterator<Item = &'a Z8>>(iter: I) -> Z8 {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Z8 {
+    fn product<I: Iterator<Item = Z8>>(iter: I) -> Z8 {
+        iter.fold(<Z8 as KarccInt>::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a Z8> for Z8 {
+    fn product<I: Iterator<Item = &'a Z8>>(iter: I) -> Z8 {
+        iter.copied().product()
+    }
+}
+
+impl From<N8> for Z8 {
+    /// Reinterprets the bits as two's complement, so `N8::MAX` becomes `-1`.
+    fn from(value: N8) -> Self {
+        Z8 { bits: value.bits }
+    }
+}
+
+impl From<Z8> for N8 {
+    /// Reinterprets the two's-complement bits as unsigned, so `-1` becomes `N8::MAX`.
+    fn from(value: Z8) -> Self {
+        N8 { bits: value.bits }
+    }
+}
+
+impl Z8 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &Z8) -> Bool {
+        Bool::new(self == other)
+    }
+
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &Z8) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
+
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &Z8) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &Z8) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &Z8) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
+    }
+}
+
+impl Z8 {
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = i8::from(*self) as u8;
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
+    }
+}
+
+impl Z8 {
+    /// Wrapping shift left: `n` is taken modulo 8, matching `i8::wrapping_shl`.
+    ///
+    /// The `<<` operator clears the value once the shift reaches 8; here a
+    /// shift by 8 leaves `self` unchanged.
+    pub fn wrapping_shl(self, n: u32) -> Z8 {
+        i8::from(self).wrapping_shl(n).into()
+    }
+
+    /// Arithmetic wrapping shift right: `n` is taken modulo 8, matching `i8::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> Z8 {
+        i8::from(self).wrapping_shr(n).into()
+    }
+}
+
+impl Z8 {
+    /// Returns the value as `i8`; same as `i8::from(self)`.
+    pub fn to_i8(self) -> i8 {
+        i8::from(self)
+    }
+}
+
+impl Z8 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
+    }
+}
+
+// --------------------- Z16 ---------------------
+
+/// Signed 16-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Z16 {
+    bits: [Bit; 16],
+}
+
+impl Z16 {
+    /// Minimum value for Z16 (-32768).
+    pub const MIN: Z16 = {
+        let mut bits = [Bit::Zero; 16];
+        bits[15] = Bit::One;
+        Z16 { bits }
+    };
+
+    /// Maximum value for Z16 (32767).
+    pub const MAX: Z16 = {
+        let mut bits = [Bit::One; 16];
+        bits[15] = Bit::Zero;
+        Z16 { bits }
+    };
+}
+
+impl From<i16> for Z16 {
+    fn from(value: i16) -> Self {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
+            if (value & (1 << i)) != 0 {
+                bits[i] = Bit::One;
+            }
+        }
+        Z16 { bits }
+    }
+}
+
+impl From<Z16> for i16 {
+    fn from(value: Z16) -> Self {
+        let mut result: i16 = 0;
+        for i in 0..16 {
+            if value.bits[i] == Bit::One {
+                result |= 1 << i;
+            }
+        }
+        result
+    }
+}
+
+impl Add for Z16 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        i16::from(self).wrapping_add(i16::from(rhs)).into()
+    }
+}
+
+impl Sub for Z16 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        i16::from(self).wrapping_sub(i16::from(rhs)).into()
+    }
+}
+
+impl Mul for Z16 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        i16::from(self).wrapping_mul(i16::from(rhs)).into()
+    }
+}
+
+impl Div for Z16 {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        i16::from(self).wrapping_div(i16::from(rhs)).into()
+    }
+}
+
+impl Rem for Z16 {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        i16::from(self).wrapping_rem(i16::from(rhs)).into()
+    }
+}
+
+impl Not for Z16 {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        (!i16::from(self)).into()
+    }
+}
+
+impl Neg for Z16 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        i16::from(self).wrapping_neg().into()
+    }
+}
+
+impl Display for Z16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", i16::from(*self))
+    }
+}
+
+/// Shows the decimal value followed by the two's complement bits, MSB first.
+impl fmt::Debug for Z16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = i16::from(*self);
+        write!(f, "Z16({} = {:#018b})", value, value)
+    }
+}
+
+impl Z16 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: Z16) -> Z16 {
+        i16::from(self).saturating_add(i16::from(other)).into()
+    }
+
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: Z16) -> Z16 {
+        i16::from(self).saturating_sub(i16::from(other)).into()
+    }
+}
+
+impl fmt::LowerHex for Z16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&i16::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for Z16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&i16::from(*self), f)
+    }
+}
+
+impl From<Z8> for Z16 {
+    fn from(value: Z8) -> Self {
+        let mut bits = [value.bits[7]; 16];
+        bits[..8].copy_from_slice(&value.bits);
+        Z16 { bits }
+    }
+}
+
+impl BitScan for Z16 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
+    }
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl Z16 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> Z16 {
+        let mut result = Z16::from(1);
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl Z16 {
+    /// Returns the absolute value. `Z16::MIN.abs()` has no positive
+    /// counterpart and wraps back to `Z16::MIN`, like `i16::wrapping_abs`.
+    pub fn abs(self) -> Z16 {
+        i16::from(self).wrapping_abs().into()
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of the value.
+    pub fn signum(self) -> Z16 {
+        i16::from(self).signum().into()
+    }
+}
+
+impl PartialOrd for Z16 {
+    fn partial_cmp(&self, other: &Z16) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Z16 {
+    fn cmp(&self, other: &Z16) -> Ordering {
+        i16::from(*self).cmp(&i16::from(*other))
+    }
+}
+
+impl core::ops::BitAnd for Z16 {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        (i16::from(self) & i16::from(rhs)).into()
+    }
+}
+
+impl core::ops::BitOr for Z16 {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        (i16::from(self) | i16::from(rhs)).into()
+    }
+}
+
+impl core::ops::BitXor for Z16 {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (i16::from(self) ^ i16::from(rhs)).into()
+    }
+}
+
+/// Shifts along the bit array; shifting by 16 or more yields zero.
+impl Shl<u8> for Z16 {
+    type Output = Self;
+    fn shl(self, rhs: u8) -> Self::Output {
+        let mut bits = [Bit::Zero; 16];
+        let shift = rhs as usize;
+        if shift >= 16 {
+            return Z16 { bits };
+        }
+        bits[shift..].copy_from_slice(&self.bits[..16 - shift]);
+        Z16 { bits }
+    }
+}
+
+/// Arithmetic shift: the sign bit is replicated into the vacated positions.
+/// Shifting by 16 or more fills every bit with the sign bit.
+impl Shr<u8> for Z16 {
+    type Output = Self;
+    fn shr(self, rhs: u8) -> Self::Output {
+        let sign = self.bits[15];
+        let mut bits = [sign; 16];
+        let shift = rhs as usize;
+        if shift >= 16 {
+            return Z16 { bits };
+        }
+        bits[..16 - shift].copy_from_slice(&self.bits[shift..]);
+        Z16 { bits }
+    }
+}
+
+impl ShlAssign<u8> for Z16 {
+    fn shl_assign(&mut self, shift: u8) {
+        *self = *self << shift;
+    }
+}
+
+impl ShrAssign<u8> for Z16 {
+    fn shr_assign(&mut self, shift: u8) {
+        *self = *self >> shift;
+    }
+}
+
+impl AddAssign for Z16 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Z16 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Z16 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Index<usize> for Z16 {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl KarccInt for Z16 {
+    type Primitive = i16;
+
+    const ZERO: Self = Z16::from_i16(0);
+    const ONE: Self = Z16::from_i16(1);
+    const MIN: Self = Z16::MIN;
+    const MAX: Self = Z16::MAX;
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i16::from(self).checked_add(i16::from(rhs)).map(Z16::from)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i16::from(self).checked_sub(i16::from(rhs)).map(Z16::from)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i16::from(self).checked_mul(i16::from(rhs)).map(Z16::from)
+    }
+}
+
+impl Z16 {
+    /// Returns the value as bytes in little-endian order, as `i16::to_le_bytes`.
+    pub fn to_le_bytes(self) -> [u8; 2] {
+        i16::from(self).to_le_bytes()
+    }
+
+    /// Returns the value as bytes in big-endian order, as `i16::to_be_bytes`.
+    pub fn to_be_bytes(self) -> [u8; 2] {
+        i16::from(self).to_be_bytes()
+    }
+
+    /// Builds a value from little-endian bytes, as `i16::from_le_bytes`.
+    pub fn from_le_bytes(bytes: [u8; 2]) -> Z16 {
+        i16::from_le_bytes(bytes).into()
+    }
+
+    /// Builds a value from big-endian bytes, as `i16::from_be_bytes`.
+    pub fn from_be_bytes(bytes: [u8; 2]) -> Z16 {
+        i16::from_be_bytes(bytes).into()
+    }
+}
+
+impl Z16 {
+    /// Reverses the order of the bytes, keeping the bits within each byte in place.
+    pub fn swap_bytes(self) -> Z16 {
+        let mut bits = [Bit::Zero; 16];
+        for (i, byte) in self.bits.chunks(8).enumerate() {
+            let start = 16 - 8 * (i + 1);
+            bits[start..start + 8].copy_from_slice(byte);
+        }
+        Z16 { bits }
+    }
+}
+
+impl Parity for Z16 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
+
+impl Z16 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &Z16) -> u32 {
+        (*self ^ *other).count_ones()
+    }
+}
+
+impl Z16 {
+    /// Creates a `Z16` from an `i16` in a const context.
+    pub const fn from_i16(value: i16) -> Self {
+        let mut bits = [Bit::Zero; 16];
+        let mut i = 0;
+        while i < 16 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+            i += 1;
+        }
+        Z16 { bits }
+    }
+}
+
+impl BitCount for Z16 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        16 - self.count_ones()
+    }
+}
+
+impl Z16 {
+    /// Euclidean division, rounding so that `rem_euclid` is never negative.
+    /// Wraps for `MIN / -1` like `/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_euclid(self, rhs: Z16) -> Z16 {
+        i16::from(self).wrapping_div_euclid(i16::from(rhs)).into()
+    }
+
+    /// Euclidean remainder, always in `0..rhs.abs()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn rem_euclid(self, rhs: Z16) -> Z16 {
+        i16::from(self).wrapping_rem_euclid(i16::from(rhs)).into()
+    }
+}
+
+impl Z16 {
+    /// Checked division. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_div(self, rhs: Z16) -> Option<Z16> {
+        i16::from(self).checked_div(i16::from(rhs)).map(Z16::from)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_rem(self, rhs: Z16) -> Option<Z16> {
+        i16::from(self).checked_rem(i16::from(rhs)).map(Z16::from)
+    }
+}
+
+impl Z16 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(16 + 16 / group.max(1));
+        for i in (0..16).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
+            }
+        }
+        text
+    }
+}
+
+impl Z16 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 16.
+    pub fn bit_range(&self, start: usize, len: usize) -> Z16 {
+        assert!(
+            start + len <= 16,
+            "bit range {}..{} out of range for 16 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 16];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        Z16 { bits }
+    }
+}
+
+impl Z16 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 16 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> Z16 {
+        assert!(index < 16, "bit index {} out of range for 16 bits", index);
+        self.bits[index] = bit;
+        self
+    }
+
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 16 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 16, "bit index {} out of range for 16 bits", index);
+        self.bits[index] = bit;
+    }
+
+    /// Clears the bit at `index`. Panics if `index` is 16 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 16, "bit index {} out of range for 16 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
+
+    /// Flips the bit at `index`. Panics if `index` is 16 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 16, "bit index {} out of range for 16 bits", index);
+        self.bits[index] = !self.bits[index];
+    }
+}
+
+impl Z16 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: Z16) -> Z16 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of two values.
+    pub fn max(self, other: Z16) -> Z16 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: Z16, hi: Z16) -> Z16 {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+impl Sum for Z16 {
+    fn sum<I: Iterator<Item = Z16>>(iter: I) -> Z16 {
+        iter.fold(<Z16 as KarccInt>::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Z16> for Z16 {
+    fn sum<I: Iterator<Item = &'a Z16>>(iter: I) -> Z16 {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Z16 {
+    fn product<I: Iterator<Item = Z16>>(iter: I) -> Z16 {
+        iter.fold(<Z16 as KarccInt>::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a Z16> for Z16 {
+    fn product<I: Iterator<Item = &'a Z16>>(iter: I) -> Z16 {
+        iter.copied().product()
+    }
+}
+
+impl From<N16> for Z16 {
+    /// Reinterprets the bits as two's complement, so `N16::MAX` becomes `-1`.
+    fn from(value: N16) -> Self {
+        Z16 { bits: value.bits }
+    }
+}
+
+impl From<Z16> for N16 {
+    /// Reinterprets the two's-complement bits as unsigned, so `-1` becomes `N16::MAX`.
+    fn from(value: Z16) -> Self {
+        N16 { bits: value.bits }
+    }
+}
+
+impl Z16 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &Z16) -> Bool {
+        Bool::new(self == other)
+    }
+
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &Z16) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
+
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &Z16) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &Z16) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &Z16) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
+    }
+}
+
+impl Z16 {
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = i16::from(*self) as u16;
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
+    }
+}
+
+impl Z16 {
+    /// Wrapping shift left: `n` is taken modulo 16, matching `i16::wrapping_shl`.
+    ///
+    /// The `<<` operator clears the value once the shift reaches 16; here a
+    /// shift by 16 leaves `self` unchanged.
+    pub fn wrapping_shl(self, n: u32) -> Z16 {
+        i16::from(self).wrapping_shl(n).into()
+    }
+
+    /// Arithmetic wrapping shift right: `n` is taken modulo 16, matching `i16::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> Z16 {
+        i16::from(self).wrapping_shr(n).into()
+    }
+}
+
+impl Z16 {
+    /// Returns the value as `i16`; same as `i16::from(self)`.
+    pub fn to_i16(self) -> i16 {
+        i16::from(self)
+    }
+}
+
+impl Z16 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
+    }
+}
+
+// --------------------- Z32 ---------------------
+
+/// Signed 32-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Z32 {
+    bits: [Bit; 32],
+}
+
+impl Z32 {
+    /// Minimum value for Z32 (-2^31).
+    pub const MIN: Z32 = {
+        let mut bits = [Bit::Zero; 32];
+        bits[31] = Bit::One;
+        Z32 { bits }
+    };
+
+    /// Maximum value for Z32 (2^31 - 1).
+    pub const MAX: Z32 = {
+        let mut bits = [Bit::One; 32];
+        bits[31] = Bit::Zero;
+        Z32 { bits }
+    };
+}
+
+impl From<i32> for Z32 {
+    fn from(value: i32) -> Self {
+        let mut bits = [Bit::Zero; 32];
+        for i in 0..32 {
+            if (value & (1 << i)) != 0 {
+                bits[i] = Bit::One;
+            }
+        }
+        Z32 { bits }
+    }
+}
+
+impl From<Z32> for i32 {
+    fn from(value: Z32) -> Self {
+        let mut result: i32 = 0;
+        for i in 0..32 {
+            if value.bits[i] == Bit::One {
+                result |= 1 << i;
+            }
+        }
+        result
+    }
+}
+
+impl Add for Z32 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        i32::from(self).wrapping_add(i32::from(rhs)).into()
+    }
+}
+
+impl Sub for Z32 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        i32::from(self).wrapping_sub(i32::from(rhs)).into()
+    }
+}
+
+impl Mul for Z32 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        i32::from(self).wrapping_mul(i32::from(rhs)).into()
+    }
+}
+
+impl Div for Z32 {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        i32::from(self).wrapping_div(i32::from(rhs)).into()
+    }
+}
+
+impl Rem for Z32 {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        i32::from(self).wrapping_rem(i32::from(rhs)).into()
+    }
+}
+
+impl Not for Z32 {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        (!i32::from(self)).into()
+    }
+}
+
+impl Neg for Z32 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        i32::from(self).wrapping_neg().into()
+    }
+}
+
+impl Display for Z32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", i32::from(*self))
+    }
+}
+
+/// Shows the decimal value followed by the two's complement bits, MSB first.
+impl fmt::Debug for Z32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = i32::from(*self);
+        write!(f, "Z32({} = {:#034b})", value, value)
+    }
+}
+
+impl Z32 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: Z32) -> Z32 {
+        i32::from(self).saturating_add(i32::from(other)).into()
+    }
+
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: Z32) -> Z32 {
+        i32::from(self).saturating_sub(i32::from(other)).into()
+    }
+}
+
+impl fmt::LowerHex for Z32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&i32::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for Z32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&i32::from(*self), f)
+    }
+}
+
+impl From<Z8> for Z32 {
+    fn from(value: Z8) -> Self {
+        let mut bits = [value.bits[7]; 32];
+        bits[..8].copy_from_slice(&value.bits);
+        Z32 { bits }
+    }
+}
+
+impl From<Z16> for Z32 {
+    fn from(value: Z16) -> Self {
+        let mut bits = [value.bits[15]; 32];
+        bits[..16].copy_from_slice(&value.bits);
+        Z32 { bits }
+    }
+}
+
+impl BitScan for Z32 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
+    }
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl Z32 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> Z32 {
+        let mut result = Z32::from(1);
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl Z32 {
+    /// Returns the absolute value. `Z32::MIN.abs()` has no positive
+    /// counterpart and wraps back to `Z32::MIN`, like `i32::wrapping_abs`.
+    pub fn abs(self) -> Z32 {
+        i32::from(self).wrapping_abs().into()
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of the value.
+    pub fn signum(self) -> Z32 {
+        i32::from(self).signum().into()
+    }
+}
+
+impl PartialOrd for Z32 {
+    fn partial_cmp(&self, other: &Z32) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Z32 {
+    fn cmp(&self, other: &Z32) -> Ordering {
+        i32::from(*self).cmp(&i32::from(*other))
+    }
+}
+
+impl core::ops::BitAnd for Z32 {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        (i32::from(self) & i32::from(rhs)).into()
+    }
+}
+
+impl core::ops::BitOr for Z32 {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        (i32::from(self) | i32::from(rhs)).into()
+    }
+}
+
+impl core::ops::BitXor for Z32 {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (i32::from(self) ^ i32::from(rhs)).into()
+    }
+}
+
+impl ShlAssign<u8> for Z32 {
+    fn shl_assign(&mut self, shift: u8) {
+        *self = *self << shift;
+    }
+}
+
+impl ShrAssign<u8> for Z32 {
+    fn shr_assign(&mut self, shift: u8) {
+        *self = *self >> shift;
+    }
+}
+
+impl AddAssign for Z32 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Z32 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Z32 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Index<usize> for Z32 {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl KarccInt for Z32 {
+    type Primitive = i32;
+
+    const ZERO: Self = Z32::from_i32(0);
+    const ONE: Self = Z32::from_i32(1);
+    const MIN: Self = Z32::MIN;
+    const MAX: Self = Z32::MAX;
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i32::from(self).checked_add(i32::from(rhs)).map(Z32::from)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i32::from(self).checked_sub(i32::from(rhs)).map(Z32::from)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i32::from(self).checked_mul(i32::from(rhs)).map(Z32::from)
+    }
+}
+
+impl Z32 {
+    /// Returns the value as bytes in little-endian order, as `i32::to_le_bytes`.
+    pub fn to_le_bytes(self) -> [u8; 4] {
+        i32::from(self).to_le_bytes()
+    }
+
+    /// Returns the value as bytes in big-endian order, as `i32::to_be_bytes`.
+    pub fn to_be_bytes(self) -> [u8; 4] {
+        i32::from(self).to_be_bytes()
+    }
+
+    /// Builds a value from little-endian bytes, as `i32::from_le_bytes`.
+    pub fn from_le_bytes(bytes: [u8; 4]) -> Z32 {
+        i32::from_le_bytes(bytes).into()
+    }
+
+    /// Builds a value from big-endian bytes, as `i32::from_be_bytes`.
+    pub fn from_be_bytes(bytes: [u8; 4]) -> Z32 {
+        i32::from_be_bytes(bytes).into()
+    }
+}
+
+impl Z32 {
+    /// Reverses the order of the bytes, keeping the bits within each byte in place.
+    pub fn swap_bytes(self) -> Z32 {
+        let mut bits = [Bit::Zero; 32];
+        for (i, byte) in self.bits.chunks(8).enumerate() {
+            let start = 32 - 8 * (i + 1);
+            bits[start..start + 8].copy_from_slice(byte);
+        }
+        Z32 { bits }
+    }
+}
+
+impl Parity for Z32 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
+
+impl Z32 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &Z32) -> u32 {
+        (*self ^ *other).count_ones()
+    }
+}
+
+impl Z32 {
+    /// Creates a `Z32` from an `i32` in a const context.
+    pub const fn from_i32(value: i32) -> Self {
+        let mut bits = [Bit::Zero; 32];
+        let mut i = 0;
+        while i < 32 {
+            bits[i] = if (value & (1 << i)) != 0 {
+                Bit::One
+            } else {
+                Bit::Zero
+            };
+            i += 1;
+        }
+        Z32 { bits }
+    }
+}
+
+impl BitCount for Z32 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        32 - self.count_ones()
+    }
+}
+
+impl Z32 {
+    /// Euclidean division, rounding so that `rem_euclid` is never negative.
+    /// Wraps for `MIN / -1` like `/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_euclid(self, rhs: Z32) -> Z32 {
+        i32::from(self).wrapping_div_euclid(i32::from(rhs)).into()
+    }
+
+    /// Euclidean remainder, always in `0..rhs.abs()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn rem_euclid(self, rhs: Z32) -> Z32 {
+        i32::from(self).wrapping_rem_euclid(i32::from(rhs)).into()
+    }
+}
+
+impl Z32 {
+    /// Checked division. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_div(self, rhs: Z32) -> Option<Z32> {
+        i32::from(self).checked_div(i32::from(rhs)).map(Z32::from)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_rem(self, rhs: Z32) -> Option<Z32> {
+        i32::from(self).checked_rem(i32::from(rhs)).map(Z32::from)
+    }
+}
+
+impl Z32 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(32 + 32 / group.max(1));
+        for i in (0..32).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
+            }
+        }
+        text
+    }
+}
+
+impl Z32 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 32.
+    pub fn bit_range(&self, start: usize, len: usize) -> Z32 {
+        assert!(
+            start + len <= 32,
+            "bit range {}..{} out of range for 32 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 32];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        Z32 { bits }
+    }
+}
+
+impl Z32 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 32 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> Z32 {
+        assert!(index < 32, "bit index {} out of range for 32 bits", index);
+        self.bits[index] = bit;
+        self
+    }
+
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 32 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 32, "bit index {} out of range for 32 bits", index);
+        self.bits[index] = bit;
+    }
+
+    /// Clears the bit at `index`. Panics if `index` is 32 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 32, "bit index {} out of range for 32 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
+
+    /// Flips the bit at `index`. Panics if `index` is 32 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 32, "bit index {} out of range for 32 bits", index);
+        self.bits[index] = !self.bits[index];
+    }
+}
+
+impl Z32 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: Z32) -> Z32 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of two values.
+    pub fn max(self, other: Z32) -> Z32 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: Z32, hi: Z32) -> Z32 {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+impl Sum for Z32 {
+    fn sum<I: Iterator<Item = Z32>>(iter: I) -> Z32 {
+        iter.fold(<Z32 as KarccInt>::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Z32> for Z32 {
+    fn sum<I: Iterator<Item = &'a Z32>>(iter: I) -> Z32 {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Z32 {
+    fn product<I: Iterator<Item = Z32>>(iter: I) -> Z32 {
+        iter.fold(<Z32 as KarccInt>::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a Z32> for Z32 {
+    fn product<I: Iterator<Item = &'a Z32>>(iter: I) -> Z32 {
+        iter.copied().product()
+    }
+}
+
+impl From<N32> for Z32 {
+    /// Reinterprets the bits as two's complement, so `N32::MAX` becomes `-1`.
+    fn from(value: N32) -> Self {
+        Z32 { bits: value.bits }
+    }
+}
+
+impl From<Z32> for N32 {
+    /// Reinterprets the two's-complement bits as unsigned, so `-1` becomes `N32::MAX`.
+    fn from(value: Z32) -> Self {
+        N32 { bits: value.bits }
+    }
+}
+
+impl Z32 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &Z32) -> Bool {
+        Bool::new(self == other)
+    }
+
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &Z32) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
+
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &Z32) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &Z32) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &Z32) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
+    }
+}
+
+impl Z32 {
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = i32::from(*self) as u32;
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
+    }
+}
+
+impl Z32 {
+    /// Wrapping shift left: `n` is taken modulo 32, matching `i32::wrapping_shl`.
+    ///
+    /// The `<<` operator clears the value once the shift reaches 32; here a
+    /// shift by 32 leaves `self` unchanged.
+    pub fn wrapping_shl(self, n: u32) -> Z32 {
+        i32::from(self).wrapping_shl(n).into()
+    }
+
+    /// Arithmetic wrapping shift right: `n` is taken modulo 32, matching `i32::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> Z32 {
+        i32::from(self).wrapping_shr(n).into()
+    }
+}
+
+/// Fails only on targets where `isize` is narrower than 32 bits.
+impl TryFrom<Z32> for isize {
+    type Error = ConversionError;
+
+    fn try_from(value: Z32) -> Result<Self, Self::Error> {
+        isize::try_from(i32::from(value)).map_err(|_| ConversionError)
+    }
+}
+
+/// Fails on 64-bit targets when the value does not fit in 32 bits.
+impl TryFrom<isize> for Z32 {
+    type Error = ConversionError;
+
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        i32::try_from(value)
+            .map(Into::into)
+            .map_err(|_| ConversionError)
+    }
+}
+
+impl Z32 {
+    /// Converts to `isize` for indexing and length arithmetic.
+    ///
+    /// Never panics on 32- or 64-bit targets.
+    pub fn to_isize(self) -> isize {
+        isize::try_from(self).expect("value does not fit in isize on this target")
+    }
+}
+
+impl Z32 {
+    /// Returns the value as `i32`; same as `i32::from(self)`.
+    pub fn to_i32(self) -> i32 {
+        i32::from(self)
+    }
+}
+
+impl Z32 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
+    }
+}
+
+// --------------------- Z64 ---------------------
+
+/// Signed 64-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Z64 {
+    bits: [Bit; 64],
+}
+
+impl Z64 {
+    /// Minimum value for Z64 (-2^63).
+    pub const MIN: Z64 = {
+        let mut bits = [Bit::Zero; 64];
+        bits[63] = Bit::One;
+        Z64 { bits }
+    };
+
+    /// Maximum value for Z64 (2^63 - 1).
+    pub const MAX: Z64 = {
+        let mut bits = [Bit::One; 64];
+        bits[63] = Bit::Zero;
+        Z64 { bits }
+    };
+}
+
+impl From<i64> for Z64 {
+    fn from(value: i64) -> Self {
+        let mut bits = [Bit::Zero; 64];
+        for i in 0..64 {
+            if (value & (1 << i)) != 0 {
+                bits[i] = Bit::One;
+            }
+        }
+        Z64 { bits }
+    }
 }
 
-impl Shl<u8> for N8 {
-    type Output = N8;
+impl From<Z64> for i64 {
+    fn from(value: Z64) -> Self {
+        let mut result: i64 = 0;
+        for i in 0..64 {
+            if value.bits[i] == Bit::One {
+                result |= 1 << i;
+            }
+        }
+        result
+    }
+}
 
-    fn shl(self, shift: u8) -> Self::Output {
-        (u8::from(self) << shift).into()
+impl Add for Z64 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        i64::from(self).wrapping_add(i64::from(rhs)).into()
     }
 }
 
-impl Shr<u8> for N8 {
-    type Output = N8;
+impl Sub for Z64 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        i64::from(self).wrapping_sub(i64::from(rhs)).into()
+    }
+}
 
-    fn shr(self, shift: u8) -> Self::Output {
-        (u8::from(self) >> shift).into()
+impl Mul for Z64 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        i64::from(self).wrapping_mul(i64::from(rhs)).into()
     }
 }
 
-impl ShlAssign<u8> for N8 {
+impl Div for Z64 {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        i64::from(self).wrapping_div(i64::from(rhs)).into()
+    }
+}
+
+impl Rem for Z64 {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        i64::from(self).wrapping_rem(i64::from(rhs)).into()
+    }
+}
+
+impl Not for Z64 {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        (!i64::from(self)).into()
+    }
+}
+
+impl Neg for Z64 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        i64::from(self).wrapping_neg().into()
+    }
+}
+
+impl Display for Z64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", i64::from(*self))
+    }
+}
+
+/// Shows the decimal value followed by the two's complement bits, MSB first.
+impl fmt::Debug for Z64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = i64::from(*self);
+        write!(f, "Z64({} = {:#066b})", value, value)
+    }
+}
+
+impl Z64 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: Z64) -> Z64 {
+        i64::from(self).saturating_add(i64::from(other)).into()
+    }
+
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: Z64) -> Z64 {
+        i64::from(self).saturating_sub(i64::from(other)).into()
+    }
+}
+
+impl fmt::LowerHex for Z64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&i64::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for Z64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&i64::from(*self), f)
+    }
+}
+
+impl From<Z8> for Z64 {
+    fn from(value: Z8) -> Self {
+        let mut bits = [value.bits[7]; 64];
+        bits[..8].copy_from_slice(&value.bits);
+        Z64 { bits }
+    }
+}
+
+impl From<Z16> for Z64 {
+    fn from(value: Z16) -> Self {
+        let mut bits = [value.bits[15]; 64];
+        bits[..16].copy_from_slice(&value.bits);
+        Z64 { bits }
+    }
+}
+
+impl From<Z32> for Z64 {
+    fn from(value: Z32) -> Self {
+        let mut bits = [value.bits[31]; 64];
+        bits[..32].copy_from_slice(&value.bits);
+        Z64 { bits }
+    }
+}
+
+impl BitScan for Z64 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
+    }
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl Z64 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> Z64 {
+        let mut result = Z64::from(1);
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl Z64 {
+    /// Returns the absolute value. `Z64::MIN.abs()` has no positive
+    /// counterpart and wraps back to `Z64::MIN`, like `i64::wrapping_abs`.
+    pub fn abs(self) -> Z64 {
+        i64::from(self).wrapping_abs().into()
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of the value.
+    pub fn signum(self) -> Z64 {
+        i64::from(self).signum().into()
+    }
+}
+
+impl PartialOrd for Z64 {
+    fn partial_cmp(&self, other: &Z64) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Z64 {
+    fn cmp(&self, other: &Z64) -> Ordering {
+        i64::from(*self).cmp(&i64::from(*other))
+    }
+}
+
+impl core::ops::BitAnd for Z64 {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        (i64::from(self) & i64::from(rhs)).into()
+    }
+}
+
+impl core::ops::BitOr for Z64 {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        (i64::from(self) | i64::from(rhs)).into()
+    }
+}
+
+impl core::ops::BitXor for Z64 {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (i64::from(self) ^ i64::from(rhs)).into()
+    }
+}
+
+/// Shifts along the bit array; shifting by 64 or more yields zero.
+impl Shl<u8> for Z64 {
+    type Output = Self;
+    fn shl(self, rhs: u8) -> Self::Output {
+        let mut bits = [Bit::Zero; 64];
+        let shift = rhs as usize;
+        if shift >= 64 {
+            return Z64 { bits };
+        }
+        bits[shift..].copy_from_slice(&self.bits[..64 - shift]);
+        Z64 { bits }
+    }
+}
+
+/// Arithmetic shift: the sign bit is replicated into the vacated positions.
+/// Shifting by 64 or more fills every bit with the sign bit.
+impl Shr<u8> for Z64 {
+    type Output = Self;
+    fn shr(self, rhs: u8) -> Self::Output {
+        let sign = self.bits[63];
+        let mut bits = [sign; 64];
+        let shift = rhs as usize;
+        if shift >= 64 {
+            return Z64 { bits };
+        }
+        bits[..64 - shift].copy_from_slice(&self.bits[shift..]);
+        Z64 { bits }
+    }
+}
+
+impl ShlAssign<u8> for Z64 {
     fn shl_assign(&mut self, shift: u8) {
         *self = *self << shift;
     }
 }
 
-impl ShrAssign<u8> for N8 {
+impl ShrAssign<u8> for Z64 {
     fn shr_assign(&mut self, shift: u8) {
         *self = *self >> shift;
     }
 }
 
-/// Full adder for single bits.
-fn full_adder(a: Bit, b: Bit, carry: Bit) -> (Bit, Bit) {
-    let sum = a ^ b ^ carry;
-    let new_carry = (a & b) | (a & carry) | (b & carry);
-    (sum, new_carry)
+impl AddAssign for Z64 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
 }
 
-impl Add for N8 {
-    type Output = N8;
+impl SubAssign for Z64 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
 
-    fn add(self, other: N8) -> N8 {
-        let mut carry = Bit::Zero;
-        let mut result_bits = [Bit::Zero; 8];
-        for i in 0..8 {
-            let (sum, new_carry) = full_adder(self.bits[i], other.bits[i], carry);
-            result_bits[i] = sum;
-            carry = new_carry;
+impl MulAssign for Z64 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Index<usize> for Z64 {
+    type Output = Bit;
+
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
+    }
+}
+
+impl KarccInt for Z64 {
+    type Primitive = i64;
+
+    const ZERO: Self = Z64::from_i64(0);
+    const ONE: Self = Z64::from_i64(1);
+    const MIN: Self = Z64::MIN;
+    const MAX: Self = Z64::MAX;
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i64::from(self).checked_add(i64::from(rhs)).map(Z64::from)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i64::from(self).checked_sub(i64::from(rhs)).map(Z64::from)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i64::from(self).checked_mul(i64::from(rhs)).map(Z64::from)
+    }
+}
+
+impl Z64 {
+    /// Returns the value as bytes in little-endian order, as `i64::to_le_bytes`.
+    pub fn to_le_bytes(self) -> [u8; 8] {
+        i64::from(self).to_le_bytes()
+    }
+
+    /// Returns the value as bytes in big-endian order, as `i64::to_be_bytes`.
+    pub fn to_be_bytes(self) -> [u8; 8] {
+        i64::from(self).to_be_bytes()
+    }
+
+    /// Builds a value from little-endian bytes, as `i64::from_le_bytes`.
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Z64 {
+        i64::from_le_bytes(bytes).into()
+    }
+
+    /// Builds a value from big-endian bytes, as `i64::from_be_bytes`.
+    pub fn from_be_bytes(bytes: [u8; 8]) -> Z64 {
+        i64::from_be_bytes(bytes).into()
+    }
+}
+
+impl Z64 {
+    /// Reverses the order of the bytes, keeping the bits within each byte in place.
+    pub fn swap_bytes(self) -> Z64 {
+        let mut bits = [Bit::Zero; 64];
+        for (i, byte) in self.bits.chunks(8).enumerate() {
+            let start = 64 - 8 * (i + 1);
+            bits[start..start + 8].copy_from_slice(byte);
         }
-        N8 { bits: result_bits }
+        Z64 { bits }
     }
 }
 
-impl AddAssign for N8 {
-    fn add_assign(&mut self, other: N8) {
-        *self = *self + other;
+impl Parity for Z64 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
     }
 }
 
-impl From<u8> for N8 {
-    fn from(value: u8) -> Self {
-        let mut bits = [Bit::Zero; 8];
-        for i in 0..8 {
+impl Z64 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &Z64) -> u32 {
+        (*self ^ *other).count_ones()
+    }
+}
+
+impl Z64 {
+    /// Creates a `Z64` from an `i64` in a const context.
+    pub const fn from_i64(value: i64) -> Self {
+        let mut bits = [Bit::Zero; 64];
+        let mut i = 0;
+        while i < 64 {
             bits[i] = if (value & (1 << i)) != 0 {
                 Bit::One
             } else {
                 Bit::Zero
             };
+            i += 1;
         }
-        N8 { bits }
+        Z64 { bits }
+    }
+}
+
+impl BitCount for Z64 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+    }
+    fn count_zeros(&self) -> u32 {
+        64 - self.count_ones()
+    }
+}
+
+impl Z64 {
+    /// Euclidean division, rounding so that `rem_euclid` is never negative.
+    /// Wraps for `MIN / -1` like `/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_euclid(self, rhs: Z64) -> Z64 {
+        i64::from(self).wrapping_div_euclid(i64::from(rhs)).into()
+    }
+
+    /// Euclidean remainder, always in `0..rhs.abs()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn rem_euclid(self, rhs: Z64) -> Z64 {
+        i64::from(self).wrapping_rem_euclid(i64::from(rhs)).into()
+    }
+}
+
+impl Z64 {
+    /// Checked division. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_div(self, rhs: Z64) -> Option<Z64> {
+        i64::from(self).checked_div(i64::from(rhs)).map(Z64::from)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_rem(self, rhs: Z64) -> Option<Z64> {
+        i64::from(self).checked_rem(i64::from(rhs)).map(Z64::from)
     }
 }
 
-impl From<N8> for u8 {
-    fn from(value: N8) -> Self {
-        let mut result = 0u8;
-        for i in 0..8 {
-            if value.bits[i] == Bit::One {
-                result |= 1 << i;
+impl Z64 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(64 + 64 / group.max(1));
+        for i in (0..64).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
             }
         }
-        result
+        text
+    }
+}
+
+impl Z64 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 64.
+    pub fn bit_range(&self, start: usize, len: usize) -> Z64 {
+        assert!(
+            start + len <= 64,
+            "bit range {}..{} out of range for 64 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 64];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        Z64 { bits }
     }
 }
 
-impl N8 {
-    /// Creates a `N8` from a primitive `u8` (const).
-    pub const fn from_u8(value: u8) -> Self {
-        let mut bits = [Bit::Zero; 8];
-        let mut i = 0;
-        while i < 8 {
-            bits[i] = if (value & (1 << i)) != 0 {
-                Bit::One
-            } else {
-                Bit::Zero
-            };
-            i += 1;
-        }
-        N8 { bits }
+impl Z64 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 64 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> Z64 {
+        assert!(index < 64, "bit index {} out of range for 64 bits", index);
+        self.bits[index] = bit;
+        self
     }
 
-    /// Creates a new `N8` from an array of 8 bits.
-    pub fn new_from_bits(bits: [Bit; 8]) -> Self {
-        N8 { bits }
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 64 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 64, "bit index {} out of range for 64 bits", index);
+        self.bits[index] = bit;
     }
 
-    /// Returns the value as a `Byte`.
-    pub fn as_byte(&self) -> Byte {
-        Byte { bits: self.bits }
+    /// Clears the bit at `index`. Panics if `index` is 64 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 64, "bit index {} out of range for 64 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
+
+    /// Flips the bit at `index`. Panics if `index` is 64 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 64, "bit index {} out of range for 64 bits", index);
+        self.bits[index] = !self.bits[index];
     }
 }
 
-impl BitCount for N8 {
-    fn count_ones(&self) -> u32 {
-        self.bits.iter().filter(|&&b| b == Bit::One).count() as u32
+impl Z64 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: Z64) -> Z64 {
+        Ord::min(self, other)
     }
-    fn count_zeros(&self) -> u32 {
-        self.bits.iter().filter(|&&b| b == Bit::Zero).count() as u32
+
+    /// Returns the larger of two values.
+    pub fn max(self, other: Z64) -> Z64 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: Z64, hi: Z64) -> Z64 {
+        Ord::clamp(self, lo, hi)
     }
 }
 
-impl BitwiseReverse for N8 {
-    fn reverse_bits(&mut self) {
-        self.bits.reverse();
+impl Sum for Z64 {
+    fn sum<I: Iterator<Item = Z64>>(iter: I) -> Z64 {
+        iter.fold(<Z64 as KarccInt>::ZERO, |acc, x| acc + x)
     }
 }
 
-impl BitwiseRotate for N8 {
-    fn rotate_left(&mut self, n: u32) {
-        let val = u8::from(*self);
-        let rotated = val.rotate_left(n);
-        *self = rotated.into();
+impl<'a> Sum<&'a Z64> for Z64 {
+    fn sum<I: Iterator<Item = &'a Z64>>(iter: I) -> Z64 {
+        iter.copied().sum()
     }
-    fn rotate_right(&mut self, n: u32) {
-        let val = u8::from(*self);
-        let rotated = val.rotate_right(n);
-        *self = rotated.into();
+}
+
+impl Product for Z64 {
+    fn product<I: Iterator<Item = Z64>>(iter: I) -> Z64 {
+        iter.fold(<Z64 as KarccInt>::ONE, |acc, x| acc * x)
     }
 }
 
-impl FromStr for N8 {
-    type Err = ();
+impl<'a> Product<&'a Z64> for Z64 {
+    fn product<I: Iterator<Item = &'a Z64>>(iter: I) -> Z64 {
+        iter.copied().product()
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() > 8 {
-            return Err(());
-        }
-        let mut bits = [Bit::Zero; 8];
-        for (i, c) in s.chars().rev().enumerate() {
-            match c {
-                '0' => bits[i] = Bit::Zero,
-                '1' => bits[i] = Bit::One,
-                _ => return Err(()),
-            }
-        }
-        Ok(N8 { bits })
+impl From<N64> for Z64 {
+    /// Reinterprets the bits as two's complement, so `N64::MAX` becomes `-1`.
+    fn from(value: N64) -> Self {
+        Z64 { bits: value.bits }
     }
 }
 
-/// Full subtractor for single bits.
-fn full_subtractor(a: Bit, b: Bit, borrow: Bit) -> (Bit, Bit) {
-    let diff = a ^ b ^ borrow;
-    let new_borrow = (b & !a) | (borrow & !(a ^ b));
-    (diff, new_borrow)
+impl From<Z64> for N64 {
+    /// Reinterprets the two's-complement bits as unsigned, so `-1` becomes `N64::MAX`.
+    fn from(value: Z64) -> Self {
+        N64 { bits: value.bits }
+    }
 }
 
-impl Sub for N8 {
-    type Output = N8;
+impl Z64 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &Z64) -> Bool {
+        Bool::new(self == other)
+    }
 
-    fn sub(self, other: N8) -> N8 {
-        let mut borrow = Bit::Zero;
-        let mut result_bits = [Bit::Zero; 8];
-        for i in 0..8 {
-            let (diff, new_borrow) = full_subtractor(self.bits[i], other.bits[i], borrow);
-            result_bits[i] = diff;
-            borrow = new_borrow;
-        }
-        N8 { bits: result_bits }
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &Z64) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
+
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &Z64) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &Z64) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
+    }
+
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &Z64) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
     }
 }
 
-impl PartialOrd for N8 {
-    fn partial_cmp(&self, other: &N8) -> Option<Ordering> {
-        Some(self.cmp(other))
+impl Z64 {
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = i64::from(*self) as u64;
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
     }
 }
 
-impl Ord for N8 {
-    fn cmp(&self, other: &N8) -> Ordering {
-        u8::from(*self).cmp(&u8::from(*other))
+impl Z64 {
+    /// Wrapping shift left: `n` is taken modulo 64, matching `i64::wrapping_shl`.
+    ///
+    /// The `<<` operator clears the value once the shift reaches 64; here a
+    /// shift by 64 leaves `self` unchanged.
+    pub fn wrapping_shl(self, n: u32) -> Z64 {
+        i64::from(self).wrapping_shl(n).into()
+    }
+
+    /// Arithmetic wrapping shift right: `n` is taken modulo 64, matching `i64::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> Z64 {
+        i64::from(self).wrapping_shr(n).into()
     }
 }
 
-impl SubAssign for N8 {
-    fn sub_assign(&mut self, other: N8) {
-        *self = *self - other;
+/// Fails on targets where `isize` is narrower than 64 bits and the value does not fit.
+impl TryFrom<Z64> for isize {
+    type Error = ConversionError;
+
+    fn try_from(value: Z64) -> Result<Self, Self::Error> {
+        isize::try_from(i64::from(value)).map_err(|_| ConversionError)
     }
 }
 
-impl Mul for N8 {
-    type Output = N8;
+/// Never fails on targets where `isize` is at most 64 bits wide.
+impl TryFrom<isize> for Z64 {
+    type Error = ConversionError;
 
-    fn mul(self, other: N8) -> N8 {
-        (u8::from(self) * u8::from(other)).into()
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        i64::try_from(value)
+            .map(Into::into)
+            .map_err(|_| ConversionError)
     }
 }
 
-impl Div for N8 {
-    type Output = N8;
+impl Z64 {
+    /// Converts to `isize` for indexing and length arithmetic.
+    ///
+    /// Panics on 32-bit targets when the value does not fit in `isize`.
+    pub fn to_isize(self) -> isize {
+        isize::try_from(self).expect("value does not fit in isize on this target")
+    }
+}
 
-    fn div(self, other: N8) -> N8 {
-        (u8::from(self) / u8::from(other)).into()
+impl Z64 {
+    /// Returns the value as `i64`; same as `i64::from(self)`.
+    pub fn to_i64(self) -> i64 {
+        i64::from(self)
     }
 }
 
-impl Display for N8 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", u8::from(*self))
+impl Z64 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
     }
 }
 
-// -------------------- N16 --------------------
+// --------------------- Z128 ---------------------
 
-/// Unsigned 16-bit integer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct N16 {
-    bits: [Bit; 16],
+/// Signed 128-bit integer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Z128 {
+    bits: [Bit; 128],
 }
 
-impl From<u16> for N16 {
-    fn from(value: u16) -> Self {
-        let mut bits = [Bit::Zero; 16];
-        for i in 0..16 {
-            bits[i] = if (value & (1 << i)) != 0 {
-                Bit::One
-            } else {
-                Bit::Zero
-            };
+impl Z128 {
+    /// Minimum value for Z128 (-2^127).
+    pub const MIN: Z128 = {
+        let mut bits = [Bit::Zero; 128];
+        bits[127] = Bit::One;
+        Z128 { bits }
+    };
+
+    /// Maximum value for Z128 (2^127 - 1).
+    pub const MAX: Z128 = {
+        let mut bits = [Bit::One; 128];
+        bits[127] = Bit::Zero;
+        Z128 { bits }
+    };
+}
+
+impl From<i128> for Z128 {
+    fn from(value: i128) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        for i in 0..128 {
+            if (value & (1 << i)) != 0 {
+                bits[i] = Bit::One;
+            }
         }
-        N16 { bits }
+        Z128 { bits }
     }
 }
 
-impl From<N16> for u16 {
-    fn from(value: N16) -> Self {
-        let mut result = 0u16;
-        for i in 0..16 {
+impl From<Z128> for i128 {
+    fn from(value: Z128) -> Self {
+        let mut result: i128 = 0;
+        for i in 0..128 {
             if value.bits[i] == Bit::One {
                 result |= 1 << i;
             }
@@ -1097,601 +9562,822 @@ impl From<N16> for u16 {
     }
 }
 
-impl Sub for N16 {
-    type Output = N16;
-
-    fn sub(self, other: N16) -> N16 {
-        u16::from(self).wrapping_sub(u16::from(other)).into()
+impl Add for Z128 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        i128::from(self).wrapping_add(i128::from(rhs)).into()
     }
 }
 
-impl SubAssign for N16 {
-    fn sub_assign(&mut self, other: N16) {
-        *self = *self - other;
+impl Sub for Z128 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        i128::from(self).wrapping_sub(i128::from(rhs)).into()
     }
 }
 
-impl Add for N16 {
-    type Output = N16;
-
-    fn add(self, other: N16) -> N16 {
-        u16::from(self).wrapping_add(u16::from(other)).into()
+impl Mul for Z128 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        i128::from(self).wrapping_mul(i128::from(rhs)).into()
     }
 }
 
-impl AddAssign for N16 {
-    fn add_assign(&mut self, other: N16) {
-        *self = *self + other;
+impl Div for Z128 {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        i128::from(self).wrapping_div(i128::from(rhs)).into()
     }
 }
 
-impl Mul for N16 {
-    type Output = N32;
-
-    fn mul(self, other: N16) -> N32 {
-        (u16::from(self) as u32 * u16::from(other) as u32).into()
+impl Rem for Z128 {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        i128::from(self).wrapping_rem(i128::from(rhs)).into()
     }
 }
 
-impl Shr<u8> for N16 {
-    type Output = N16;
-
-    fn shr(self, shift: u8) -> N16 {
-        (u16::from(self) >> shift).into()
+impl Not for Z128 {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        (!i128::from(self)).into()
     }
 }
 
-impl Shl<u8> for N16 {
-    type Output = N16;
-
-    fn shl(self, shift: u8) -> N16 {
-        (u16::from(self) << shift).into()
+impl Neg for Z128 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        i128::from(self).wrapping_neg().into()
     }
 }
 
-impl ShlAssign<u8> for N16 {
-    fn shl_assign(&mut self, shift: u8) {
-        *self = *self << shift;
+impl Display for Z128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", i128::from(*self))
     }
 }
 
-impl ShrAssign<u8> for N16 {
-    fn shr_assign(&mut self, shift: u8) {
-        *self = *self >> shift;
+/// Shows the decimal value followed by the two's complement bits, MSB first.
+impl fmt::Debug for Z128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = i128::from(*self);
+        write!(f, "Z128({} = {:#0130b})", value, value)
     }
 }
 
-impl Div for N16 {
-    type Output = N16;
-
-    fn div(self, other: N16) -> N16 {
-        (u16::from(self) / u16::from(other)).into()
+impl Z128 {
+    /// Saturating addition. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_add(self, other: Z128) -> Z128 {
+        i128::from(self).saturating_add(i128::from(other)).into()
     }
-}
 
-impl PartialOrd for N16 {
-    fn partial_cmp(&self, other: &N16) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// Saturating subtraction. Clamps to the numeric bounds instead of wrapping.
+    pub fn saturating_sub(self, other: Z128) -> Z128 {
+        i128::from(self).saturating_sub(i128::from(other)).into()
     }
 }
 
-impl Ord for N16 {
-    fn cmp(&self, other: &N16) -> Ordering {
-        u16::from(*self).cmp(&u16::from(*other))
+impl fmt::LowerHex for Z128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&i128::from(*self), f)
     }
 }
 
-impl N16 {
-    /// Creates a new `N16` from an array of 16 bits.
-    pub fn new(bits: [Bit; 16]) -> Self {
-        N16 { bits }
-    }
-    /// Returns a zero value for N16.
-    pub fn zero() -> Self {
-        N16 {
-            bits: [Bit::Zero; 16],
-        }
+impl fmt::UpperHex for Z128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&i128::from(*self), f)
     }
 }
 
-impl BitCount for N16 {
-    fn count_ones(&self) -> u32 {
-        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
-    }
-    fn count_zeros(&self) -> u32 {
-        self.bits.iter().filter(|&bit| *bit == Bit::Zero).count() as u32
+impl From<Z8> for Z128 {
+    fn from(value: Z8) -> Self {
+        let mut bits = [value.bits[7]; 128];
+        bits[..8].copy_from_slice(&value.bits);
+        Z128 { bits }
     }
 }
 
-impl BitwiseReverse for N16 {
-    fn reverse_bits(&mut self) {
-        self.bits.reverse();
+impl From<Z16> for Z128 {
+    fn from(value: Z16) -> Self {
+        let mut bits = [value.bits[15]; 128];
+        bits[..16].copy_from_slice(&value.bits);
+        Z128 { bits }
     }
 }
 
-impl Display for N16 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", u16::from(*self))
+impl From<Z32> for Z128 {
+    fn from(value: Z32) -> Self {
+        let mut bits = [value.bits[31]; 128];
+        bits[..32].copy_from_slice(&value.bits);
+        Z128 { bits }
     }
 }
 
-// ---------------- N32 --------------------
-
-/// Unsigned 32-bit integer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct N32 {
-    bits: [Bit; 32],
+impl From<Z64> for Z128 {
+    fn from(value: Z64) -> Self {
+        let mut bits = [value.bits[63]; 128];
+        bits[..64].copy_from_slice(&value.bits);
+        Z128 { bits }
+    }
 }
 
-impl N32 {
-    /// Creates a new `N32` from an array of 32 bits.
-    pub fn new(bits: [Bit; 32]) -> Self {
-        N32 { bits }
+impl BitScan for Z128 {
+    fn leading_zeros(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::Zero)
+            .count() as u32
     }
-    /// Returns a zero value for N32.
-    pub fn zero() -> Self {
-        N32 {
-            bits: [Bit::Zero; 32],
+    fn trailing_zeros(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::Zero).count() as u32
+    }
+    fn leading_ones(&self) -> u32 {
+        self.bits
+            .iter()
+            .rev()
+            .take_while(|&&b| b == Bit::One)
+            .count() as u32
+    }
+    fn trailing_ones(&self) -> u32 {
+        self.bits.iter().take_while(|&&b| b == Bit::One).count() as u32
+    }
+}
+
+impl Z128 {
+    /// Raises `self` to the power `exp` by repeated squaring, wrapping on overflow.
+    pub fn pow(self, exp: u32) -> Z128 {
+        let mut result = Z128::from(1);
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
         }
+        result
     }
 }
 
-impl BitCount for N32 {
-    fn count_ones(&self) -> u32 {
-        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
+impl Z128 {
+    /// Returns the absolute value. `Z128::MIN.abs()` has no positive
+    /// counterpart and wraps back to `Z128::MIN`, like `i128::wrapping_abs`.
+    pub fn abs(self) -> Z128 {
+        i128::from(self).wrapping_abs().into()
     }
-    fn count_zeros(&self) -> u32 {
-        self.bits.iter().filter(|&bit| *bit == Bit::Zero).count() as u32
+
+    /// Returns `-1`, `0` or `1` depending on the sign of the value.
+    pub fn signum(self) -> Z128 {
+        i128::from(self).signum().into()
     }
 }
 
-impl Display for N32 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", u32::from(*self))
+impl PartialOrd for Z128 {
+    fn partial_cmp(&self, other: &Z128) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl From<u32> for N32 {
-    fn from(value: u32) -> Self {
-        let mut bits = [Bit::Zero; 32];
-        for i in 0..32 {
-            bits[i] = if (value & (1 << i)) != 0 {
-                Bit::One
-            } else {
-                Bit::Zero
-            };
-        }
-        N32 { bits }
+impl Ord for Z128 {
+    fn cmp(&self, other: &Z128) -> Ordering {
+        i128::from(*self).cmp(&i128::from(*other))
     }
 }
 
-impl From<N32> for u32 {
-    fn from(value: N32) -> Self {
-        value
-            .bits
-            .iter()
-            .enumerate()
-            .fold(0, |acc, (i, &bit)| acc | (u8::from(bit) as u32) << i)
+impl core::ops::BitAnd for Z128 {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        (i128::from(self) & i128::from(rhs)).into()
     }
 }
 
-impl Add for N32 {
+impl core::ops::BitOr for Z128 {
     type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        (i128::from(self) | i128::from(rhs)).into()
+    }
+}
 
-    fn add(self, other: Self) -> Self {
-        u32::from(self).wrapping_add(u32::from(other)).into()
+impl core::ops::BitXor for Z128 {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (i128::from(self) ^ i128::from(rhs)).into()
     }
 }
 
-impl Sub for N32 {
+/// Shifts along the bit array; shifting by 128 or more yields zero.
+impl Shl<u8> for Z128 {
     type Output = Self;
+    fn shl(self, rhs: u8) -> Self::Output {
+        let mut bits = [Bit::Zero; 128];
+        let shift = rhs as usize;
+        if shift >= 128 {
+            return Z128 { bits };
+        }
+        bits[shift..].copy_from_slice(&self.bits[..128 - shift]);
+        Z128 { bits }
+    }
+}
 
-    fn sub(self, other: Self) -> Self {
-        u32::from(self).wrapping_sub(u32::from(other)).into()
+/// Arithmetic shift: the sign bit is replicated into the vacated positions.
+/// Shifting by 128 or more fills every bit with the sign bit.
+impl Shr<u8> for Z128 {
+    type Output = Self;
+    fn shr(self, rhs: u8) -> Self::Output {
+        let sign = self.bits[127];
+        let mut bits = [sign; 128];
+        let shift = rhs as usize;
+        if shift >= 128 {
+            return Z128 { bits };
+        }
+        bits[..128 - shift].copy_from_slice(&self.bits[shift..]);
+        Z128 { bits }
     }
 }
 
-impl SubAssign for N32 {
-    fn sub_assign(&mut self, other: Self) {
-        *self = *self - other;
+impl ShlAssign<u8> for Z128 {
+    fn shl_assign(&mut self, shift: u8) {
+        *self = *self << shift;
     }
 }
 
-impl AddAssign for N32 {
+impl ShrAssign<u8> for Z128 {
+    fn shr_assign(&mut self, shift: u8) {
+        *self = *self >> shift;
+    }
+}
+
+impl AddAssign for Z128 {
     fn add_assign(&mut self, other: Self) {
         *self = *self + other;
     }
 }
 
-impl Mul for N32 {
-    type Output = Self;
-
-    fn mul(self, other: Self) -> Self {
-        u32::from(self).wrapping_mul(u32::from(other)).into()
+impl SubAssign for Z128 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
     }
 }
 
-impl MulAssign for N32 {
+impl MulAssign for Z128 {
     fn mul_assign(&mut self, other: Self) {
         *self = *self * other;
     }
 }
 
-impl Div for N32 {
-    type Output = Self;
+impl Index<usize> for Z128 {
+    type Output = Bit;
 
-    fn div(self, other: Self) -> Self {
-        (u32::from(self) / u32::from(other)).into()
+    fn index(&self, index: usize) -> &Bit {
+        &self.bits[index]
     }
 }
 
-impl DivAssign for N32 {
-    fn div_assign(&mut self, other: Self) {
-        *self = *self / other;
+impl KarccInt for Z128 {
+    type Primitive = i128;
+
+    const ZERO: Self = Z128::from_i128(0);
+    const ONE: Self = Z128::from_i128(1);
+    const MIN: Self = Z128::MIN;
+    const MAX: Self = Z128::MAX;
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i128::from(self)
+            .checked_add(i128::from(rhs))
+            .map(Z128::from)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i128::from(self)
+            .checked_sub(i128::from(rhs))
+            .map(Z128::from)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i128::from(self)
+            .checked_mul(i128::from(rhs))
+            .map(Z128::from)
     }
 }
 
-impl Rem for N32 {
-    type Output = Self;
+impl Z128 {
+    /// Returns the value as bytes in little-endian order, as `i128::to_le_bytes`.
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        i128::from(self).to_le_bytes()
+    }
 
-    fn rem(self, other: Self) -> Self {
-        (u32::from(self) % u32::from(other)).into()
+    /// Returns the value as bytes in big-endian order, as `i128::to_be_bytes`.
+    pub fn to_be_bytes(self) -> [u8; 16] {
+        i128::from(self).to_be_bytes()
+    }
+
+    /// Builds a value from little-endian bytes, as `i128::from_le_bytes`.
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Z128 {
+        i128::from_le_bytes(bytes).into()
+    }
+
+    /// Builds a value from big-endian bytes, as `i128::from_be_bytes`.
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Z128 {
+        i128::from_be_bytes(bytes).into()
     }
 }
 
-impl RemAssign for N32 {
-    fn rem_assign(&mut self, other: Self) {
-        *self = *self % other;
+impl Z128 {
+    /// Reverses the order of the bytes, keeping the bits within each byte in place.
+    pub fn swap_bytes(self) -> Z128 {
+        let mut bits = [Bit::Zero; 128];
+        for (i, byte) in self.bits.chunks(8).enumerate() {
+            let start = 128 - 8 * (i + 1);
+            bits[start..start + 8].copy_from_slice(byte);
+        }
+        Z128 { bits }
     }
 }
 
-// ---------------- N64 --------------------
+impl Parity for Z128 {
+    fn parity(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, &bit| acc ^ bit)
+    }
+}
 
-/// Unsigned 64-bit integer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct N64 {
-    bits: [Bit; 64],
+impl Z128 {
+    /// Returns the number of bit positions in which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &Z128) -> u32 {
+        (*self ^ *other).count_ones()
+    }
 }
 
-impl From<u64> for N64 {
-    fn from(value: u64) -> Self {
-        let mut bits = [Bit::Zero; 64];
-        for i in 0..64 {
+impl Z128 {
+    /// Creates a `Z128` from an `i128` in a const context.
+    pub const fn from_i128(value: i128) -> Self {
+        let mut bits = [Bit::Zero; 128];
+        let mut i = 0;
+        while i < 128 {
             bits[i] = if (value & (1 << i)) != 0 {
                 Bit::One
             } else {
                 Bit::Zero
             };
+            i += 1;
         }
-        N64 { bits }
+        Z128 { bits }
     }
 }
 
-impl From<N64> for u64 {
-    fn from(value: N64) -> Self {
-        value
-            .bits
-            .iter()
-            .enumerate()
-            .fold(0, |acc, (i, &bit)| acc | (u8::from(bit) as u64) << i)
+impl BitCount for Z128 {
+    fn count_ones(&self) -> u32 {
+        self.bits.iter().filter(|&bit| *bit == Bit::One).count() as u32
     }
-}
-
-impl Add for N64 {
-    type Output = Self;
-
-    fn add(self, other: Self) -> Self {
-        u64::from(self).wrapping_add(u64::from(other)).into()
+    fn count_zeros(&self) -> u32 {
+        128 - self.count_ones()
     }
 }
 
-impl Sub for N64 {
-    type Output = Self;
-
-    fn sub(self, other: Self) -> Self {
-        u64::from(self).wrapping_sub(u64::from(other)).into()
+impl Z128 {
+    /// Euclidean division, rounding so that `rem_euclid` is never negative.
+    /// Wraps for `MIN / -1` like `/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_euclid(self, rhs: Z128) -> Z128 {
+        i128::from(self).wrapping_div_euclid(i128::from(rhs)).into()
     }
-}
-
-impl Mul for N64 {
-    type Output = Self;
 
-    fn mul(self, other: Self) -> Self {
-        u64::from(self).wrapping_mul(u64::from(other)).into()
+    /// Euclidean remainder, always in `0..rhs.abs()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn rem_euclid(self, rhs: Z128) -> Z128 {
+        i128::from(self).wrapping_rem_euclid(i128::from(rhs)).into()
     }
 }
 
-impl Div for N64 {
-    type Output = Self;
+impl Z128 {
+    /// Checked division. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_div(self, rhs: Z128) -> Option<Z128> {
+        i128::from(self)
+            .checked_div(i128::from(rhs))
+            .map(Z128::from)
+    }
 
-    fn div(self, other: Self) -> Self {
-        (u64::from(self) / u64::from(other)).into()
+    /// Checked remainder. Returns `None` if `rhs` is zero or on `MIN / -1`.
+    pub fn checked_rem(self, rhs: Z128) -> Option<Z128> {
+        i128::from(self)
+            .checked_rem(i128::from(rhs))
+            .map(Z128::from)
     }
 }
 
-impl AddAssign for N64 {
-    fn add_assign(&mut self, other: Self) {
-        *self = *self + other;
+impl Z128 {
+    /// Formats the bits MSB-first, inserting `sep` every `group` bits counted
+    /// from the least significant end. A `group` of zero disables grouping.
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_binary(&self, group: usize, sep: char) -> String {
+        let mut text = String::with_capacity(128 + 128 / group.max(1));
+        for i in (0..128).rev() {
+            text.push(if self.bits[i] == Bit::One { '1' } else { '0' });
+            if group > 0 && i > 0 && i % group == 0 {
+                text.push(sep);
+            }
+        }
+        text
+    }
+}
+
+impl Z128 {
+    /// Extracts `len` bits starting at bit `start`, right-aligned and zero-filled above.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + len` exceeds 128.
+    pub fn bit_range(&self, start: usize, len: usize) -> Z128 {
+        assert!(
+            start + len <= 128,
+            "bit range {}..{} out of range for 128 bits",
+            start,
+            start + len
+        );
+        let mut bits = [Bit::Zero; 128];
+        bits[..len].copy_from_slice(&self.bits[start..start + len]);
+        Z128 { bits }
     }
 }
 
-impl SubAssign for N64 {
-    fn sub_assign(&mut self, other: Self) {
-        *self = *self - other;
+impl Z128 {
+    /// Returns a copy with the bit at `index` replaced by `bit`.
+    /// Panics if `index` is 128 or more.
+    pub fn with_bit(mut self, index: usize, bit: Bit) -> Z128 {
+        assert!(index < 128, "bit index {} out of range for 128 bits", index);
+        self.bits[index] = bit;
+        self
     }
-}
 
-impl Display for N64 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", u64::from(*self))
+    /// Sets the bit at `index` to `bit`. Panics if `index` is 128 or more.
+    pub fn set_bit(&mut self, index: usize, bit: Bit) {
+        assert!(index < 128, "bit index {} out of range for 128 bits", index);
+        self.bits[index] = bit;
     }
-}
 
-// --------------------- Z8 ---------------------
+    /// Clears the bit at `index`. Panics if `index` is 128 or more.
+    pub fn clear_bit(&mut self, index: usize) {
+        assert!(index < 128, "bit index {} out of range for 128 bits", index);
+        self.bits[index] = Bit::Zero;
+    }
 
-/// Signed 8-bit integer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Z8 {
-    bits: [Bit; 8],
+    /// Flips the bit at `index`. Panics if `index` is 128 or more.
+    pub fn toggle_bit(&mut self, index: usize) {
+        assert!(index < 128, "bit index {} out of range for 128 bits", index);
+        self.bits[index] = !self.bits[index];
+    }
 }
 
-impl From<i8> for Z8 {
-    fn from(value: i8) -> Self {
-        let mut bits = [Bit::Zero; 8];
-        for i in 0..8 {
-            if (value & (1 << i)) != 0 {
-                bits[i] = Bit::One;
-            }
-        }
-        Z8 { bits }
+impl Z128 {
+    /// Returns the smaller of two values.
+    pub fn min(self, other: Z128) -> Z128 {
+        Ord::min(self, other)
     }
-}
 
-impl From<Z8> for i8 {
-    fn from(value: Z8) -> Self {
-        let mut result: i8 = 0;
-        for i in 0..8 {
-            if value.bits[i] == Bit::One {
-                result |= 1 << i;
-            }
-        }
-        result
+    /// Returns the larger of two values.
+    pub fn max(self, other: Z128) -> Z128 {
+        Ord::max(self, other)
     }
-}
 
-impl Add for Z8 {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        i8::from(self).wrapping_add(i8::from(rhs)).into()
+    /// Restricts the value to `lo..=hi`. Panics if `lo > hi`.
+    pub fn clamp(self, lo: Z128, hi: Z128) -> Z128 {
+        Ord::clamp(self, lo, hi)
     }
 }
 
-impl Sub for Z8 {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        i8::from(self).wrapping_sub(i8::from(rhs)).into()
+impl Sum for Z128 {
+    fn sum<I: Iterator<Item = Z128>>(iter: I) -> Z128 {
+        iter.fold(<Z128 as KarccInt>::ZERO, |acc, x| acc + x)
     }
 }
 
-impl Mul for Z8 {
-    type Output = Self;
-    fn mul(self, rhs: Self) -> Self::Output {
-        i8::from(self).wrapping_mul(i8::from(rhs)).into()
+impl<'a> Sum<&'a Z128> for Z128 {
+    fn sum<I: Iterator<Item = &'a Z128>>(iter: I) -> Z128 {
+        iter.copied().sum()
     }
 }
 
-impl Div for Z8 {
-    type Output = Self;
-    fn div(self, rhs: Self) -> Self::Output {
-        i8::from(self).wrapping_div(i8::from(rhs)).into()
+impl Product for Z128 {
+    fn product<I: Iterator<Item = Z128>>(iter: I) -> Z128 {
+        iter.fold(<Z128 as KarccInt>::ONE, |acc, x| acc * x)
     }
 }
 
-impl Rem for Z8 {
-    type Output = Self;
-    fn rem(self, rhs: Self) -> Self::Output {
-        i8::from(self).wrapping_rem(i8::from(rhs)).into()
+impl<'a> Product<&'a Z128> for Z128 {
+    fn product<I: Iterator<Item = &'a Z128>>(iter: I) -> Z128 {
+        iter.copied().product()
     }
 }
 
-impl Not for Z8 {
-    type Output = Self;
-    fn not(self) -> Self::Output {
-        (!i8::from(self)).into()
+impl From<N128> for Z128 {
+    /// Reinterprets the bits as two's complement, so `N128::MAX` becomes `-1`.
+    fn from(value: N128) -> Self {
+        Z128 { bits: value.bits }
     }
 }
 
-impl Display for Z8 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", i8::from(*self))
+impl From<Z128> for N128 {
+    /// Reinterprets the two's-complement bits as unsigned, so `-1` becomes `N128::MAX`.
+    fn from(value: Z128) -> Self {
+        N128 { bits: value.bits }
     }
 }
 
-// --------------------- Z16 ---------------------
+impl Z128 {
+    /// Compares for equality, returning a `Bool`.
+    pub fn eq_bool(&self, other: &Z128) -> Bool {
+        Bool::new(self == other)
+    }
 
-/// Signed 16-bit integer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Z16 {
-    bits: [Bit; 16],
-}
+    /// Returns `Bool::True` if `self < other`.
+    pub fn lt_bool(&self, other: &Z128) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Less)
+    }
 
-impl From<i16> for Z16 {
-    fn from(value: i16) -> Self {
-        let mut bits = [Bit::Zero; 16];
-        for i in 0..16 {
-            if (value & (1 << i)) != 0 {
-                bits[i] = Bit::One;
-            }
-        }
-        Z16 { bits }
+    /// Returns `Bool::True` if `self <= other`.
+    pub fn le_bool(&self, other: &Z128) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Greater)
     }
-}
 
-impl From<Z16> for i16 {
-    fn from(value: Z16) -> Self {
-        let mut result: i16 = 0;
-        for i in 0..16 {
-            if value.bits[i] == Bit::One {
-                result |= 1 << i;
-            }
-        }
-        result
+    /// Returns `Bool::True` if `self > other`.
+    pub fn gt_bool(&self, other: &Z128) -> Bool {
+        Bool::new(self.cmp(other) == Ordering::Greater)
     }
-}
 
-impl Add for Z16 {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        i16::from(self).wrapping_add(i16::from(rhs)).into()
+    /// Returns `Bool::True` if `self >= other`.
+    pub fn ge_bool(&self, other: &Z128) -> Bool {
+        Bool::new(self.cmp(other) != Ordering::Less)
     }
 }
 
-impl Sub for Z16 {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        i16::from(self).wrapping_sub(i16::from(rhs)).into()
+impl Z128 {
+    /// Returns the indices of the set bits in ascending order (LSB first).
+    ///
+    /// Each step clears the lowest set bit, so only set positions are visited.
+    pub fn set_bit_indices(&self) -> impl Iterator<Item = usize> {
+        let mut rest = i128::from(*self) as u128;
+        core::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let index = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            Some(index)
+        })
     }
 }
 
-impl Mul for Z16 {
-    type Output = Self;
-    fn mul(self, rhs: Self) -> Self::Output {
-        i16::from(self).wrapping_mul(i16::from(rhs)).into()
+impl Z128 {
+    /// Wrapping shift left: `n` is taken modulo 128, matching `i128::wrapping_shl`.
+    ///
+    /// The `<<` operator clears the value once the shift reaches 128; here a
+    /// shift by 128 leaves `self` unchanged.
+    pub fn wrapping_shl(self, n: u32) -> Z128 {
+        i128::from(self).wrapping_shl(n).into()
     }
-}
 
-impl Div for Z16 {
-    type Output = Self;
-    fn div(self, rhs: Self) -> Self::Output {
-        i16::from(self).wrapping_div(i16::from(rhs)).into()
+    /// Arithmetic wrapping shift right: `n` is taken modulo 128, matching `i128::wrapping_shr`.
+    pub fn wrapping_shr(self, n: u32) -> Z128 {
+        i128::from(self).wrapping_shr(n).into()
     }
 }
 
-impl Rem for Z16 {
-    type Output = Self;
-    fn rem(self, rhs: Self) -> Self::Output {
-        i16::from(self).wrapping_rem(i16::from(rhs)).into()
+impl Z128 {
+    /// Returns the value as `i128`; same as `i128::from(self)`.
+    pub fn to_i128(self) -> i128 {
+        i128::from(self)
     }
 }
 
-impl Not for Z16 {
-    type Output = Self;
-    fn not(self) -> Self::Output {
-        (!i16::from(self)).into()
+impl Z128 {
+    /// AND of every bit: `One` only if all bits are set.
+    pub fn reduce_and(&self) -> Bit {
+        self.bits.iter().fold(Bit::One, |acc, bit| acc.and(bit))
+    }
+
+    /// OR of every bit: `One` if any bit is set.
+    pub fn reduce_or(&self) -> Bit {
+        self.bits.iter().fold(Bit::Zero, |acc, bit| acc.or(bit))
+    }
+
+    /// XOR of every bit, i.e. the parity bit: `One` if an odd number of bits are set.
+    pub fn reduce_xor(&self) -> Bit {
+        self.parity()
     }
 }
 
-impl Display for Z16 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", i16::from(*self))
+// --------------------- Wrapping/Checked ---------------------
+
+/// Wrapper whose `+`, `-` and `*` wrap around at the numeric bounds,
+/// like `std::num::Wrapping`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Wrapping<T>(pub T);
+
+impl<T: KarccInt> Add for Wrapping<T> {
+    type Output = Wrapping<T>;
+
+    fn add(self, other: Self) -> Self::Output {
+        Wrapping(self.0.wrapping_add(other.0))
     }
 }
 
-// --------------------- Z32 ---------------------
+impl<T: KarccInt> Sub for Wrapping<T> {
+    type Output = Wrapping<T>;
 
-/// Signed 32-bit integer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Z32 {
-    bits: [Bit; 32],
+    fn sub(self, other: Self) -> Self::Output {
+        Wrapping(self.0.wrapping_sub(other.0))
+    }
 }
 
-impl From<i32> for Z32 {
-    fn from(value: i32) -> Self {
-        let mut bits = [Bit::Zero; 32];
-        for i in 0..32 {
-            if (value & (1 << i)) != 0 {
-                bits[i] = Bit::One;
-            }
-        }
-        Z32 { bits }
+impl<T: KarccInt> Mul for Wrapping<T> {
+    type Output = Wrapping<T>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Wrapping(self.0.wrapping_mul(other.0))
     }
 }
 
-impl From<Z32> for i32 {
-    fn from(value: Z32) -> Self {
-        let mut result: i32 = 0;
-        for i in 0..32 {
-            if value.bits[i] == Bit::One {
-                result |= 1 << i;
-            }
-        }
-        result
+impl<T: KarccInt> AddAssign for Wrapping<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
     }
 }
 
-impl Add for Z32 {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        i32::from(self).wrapping_add(i32::from(rhs)).into()
+impl<T: KarccInt> SubAssign for Wrapping<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
     }
 }
 
-impl Sub for Z32 {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        i32::from(self).wrapping_sub(i32::from(rhs)).into()
+impl<T: KarccInt> MulAssign for Wrapping<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
     }
 }
 
-impl Mul for Z32 {
-    type Output = Self;
-    fn mul(self, rhs: Self) -> Self::Output {
-        i32::from(self).wrapping_mul(i32::from(rhs)).into()
+impl<T: Display> Display for Wrapping<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
     }
 }
 
-impl Div for Z32 {
-    type Output = Self;
-    fn div(self, rhs: Self) -> Self::Output {
-        i32::from(self).wrapping_div(i32::from(rhs)).into()
+/// Wrapper whose `+`, `-` and `*` return `None` instead of overflowing.
+///
+/// The result is itself wrapped, so chains can be written with `?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Checked<T>(pub T);
+
+impl<T: KarccInt> Add for Checked<T> {
+    type Output = Option<Checked<T>>;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.0.checked_add(other.0).map(Checked)
     }
 }
 
-impl Rem for Z32 {
-    type Output = Self;
-    fn rem(self, rhs: Self) -> Self::Output {
-        i32::from(self).wrapping_rem(i32::from(rhs)).into()
+impl<T: KarccInt> Sub for Checked<T> {
+    type Output = Option<Checked<T>>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.0.checked_sub(other.0).map(Checked)
     }
 }
 
-impl Not for Z32 {
-    type Output = Self;
-    fn not(self) -> Self::Output {
-        (!i32::from(self)).into()
+impl<T: KarccInt> Mul for Checked<T> {
+    type Output = Option<Checked<T>>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.0.checked_mul(other.0).map(Checked)
     }
 }
 
-impl Display for Z32 {
+impl<T: Display> Display for Checked<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", i32::from(*self))
+        self.0.fmt(f)
     }
 }
 
-// --------------------- Z64 ---------------------
+// --------------------- R16 ---------------------
 
-/// Signed 64-bit integer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Z64 {
-    bits: [Bit; 64],
+/// 16-bit IEEE-754 binary16 (half-precision) floating-point number.
+///
+/// Arithmetic is performed in `f32` and rounded back to binary16. Equality
+/// follows IEEE-754 semantics like `R32`.
+#[derive(Debug, Clone, Copy)]
+pub struct R16 {
+    bits: [Bit; 16],
 }
 
-impl From<i64> for Z64 {
-    fn from(value: i64) -> Self {
-        let mut bits = [Bit::Zero; 64];
-        for i in 0..64 {
+/// Narrows an `f32` to binary16 bits, rounding to nearest with ties to even.
+/// Values too large for binary16 become infinity.
+fn f32_to_f16_bits(value: f32) -> u16 {
+    let x = value.to_bits();
+    let sign = ((x >> 16) & 0x8000) as u16;
+    let exp = ((x >> 23) & 0xFF) as i32;
+    let mant = x & 0x007F_FFFF;
+
+    if exp == 0xFF {
+        if mant == 0 {
+            return sign | 0x7C00;
+        }
+        // Keep NaN quiet and preserve the top payload bits.
+        return sign | 0x7E00 | (mant >> 13) as u16;
+    }
+
+    let unbiased = exp - 127;
+    if unbiased > 15 {
+        return sign | 0x7C00;
+    }
+    if unbiased >= -14 {
+        let mut half = (((unbiased + 15) as u32) << 10) | (mant >> 13);
+        let round_bits = mant & 0x1FFF;
+        if round_bits > 0x1000 || (round_bits == 0x1000 && half & 1 == 1) {
+            // A carry out of the mantissa correctly bumps the exponent,
+            // all the way to infinity for the largest values.
+            half += 1;
+        }
+        return sign | half as u16;
+    }
+    if unbiased < -25 {
+        return sign;
+    }
+
+    // Subnormal result: the half mantissa counts multiples of 2^-24.
+    let full_mant = mant | 0x0080_0000;
+    let shift = (-unbiased - 1) as u32;
+    let mut half = full_mant >> shift;
+    let rest = full_mant & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    if rest > halfway || (rest == halfway && half & 1 == 1) {
+        half += 1;
+    }
+    sign | half as u16
+}
+
+/// Widens binary16 bits to an `f32`. Every binary16 value is exactly representable.
+fn f16_bits_to_f32(half: u16) -> f32 {
+    let sign = ((half & 0x8000) as u32) << 16;
+    let exp = ((half >> 10) & 0x1F) as u32;
+    let mant = (half & 0x03FF) as u32;
+    let bits = if exp == 0x1F {
+        sign | 0x7F80_0000 | (mant << 13)
+    } else if exp == 0 {
+        if mant == 0 {
+            sign
+        } else {
+            let mut e: i32 = -14;
+            let mut m = mant;
+            while m & 0x0400 == 0 {
+                m <<= 1;
+                e -= 1;
+            }
+            sign | (((e + 127) as u32) << 23) | ((m & 0x03FF) << 13)
+        }
+    } else {
+        sign | ((exp + 127 - 15) << 23) | (mant << 13)
+    };
+    f32::from_bits(bits)
+}
+
+impl R16 {
+    /// Largest finite binary16 value (65504).
+    pub const MAX: R16 = {
+        let mut bits = [Bit::One; 16];
+        bits[10] = Bit::Zero;
+        bits[15] = Bit::Zero;
+        R16 { bits }
+    };
+
+    /// Creates an `R16` from its raw binary16 bit pattern.
+    pub fn from_bits(value: u16) -> Self {
+        let mut bits = [Bit::Zero; 16];
+        for i in 0..16 {
             if (value & (1 << i)) != 0 {
                 bits[i] = Bit::One;
             }
         }
-        Z64 { bits }
+        R16 { bits }
     }
-}
 
-impl From<Z64> for i64 {
-    fn from(value: Z64) -> Self {
-        let mut result: i64 = 0;
-        for i in 0..64 {
-            if value.bits[i] == Bit::One {
+    /// Returns the raw binary16 bit pattern.
+    pub fn to_bits(self) -> u16 {
+        let mut result: u16 = 0;
+        for i in 0..16 {
+            if self.bits[i] == Bit::One {
                 result |= 1 << i;
             }
         }
@@ -1699,58 +10385,87 @@ impl From<Z64> for i64 {
     }
 }
 
-impl Add for Z64 {
+impl From<f32> for R16 {
+    fn from(value: f32) -> Self {
+        R16::from_bits(f32_to_f16_bits(value))
+    }
+}
+
+impl From<R16> for f32 {
+    fn from(value: R16) -> Self {
+        f16_bits_to_f32(value.to_bits())
+    }
+}
+
+impl Add for R16 {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
-        i64::from(self).wrapping_add(i64::from(rhs)).into()
+        (f32::from(self) + f32::from(rhs)).into()
     }
 }
 
-impl Sub for Z64 {
+impl Sub for R16 {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
-        i64::from(self).wrapping_sub(i64::from(rhs)).into()
+        (f32::from(self) - f32::from(rhs)).into()
     }
 }
 
-impl Mul for Z64 {
+impl Mul for R16 {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
-        i64::from(self).wrapping_mul(i64::from(rhs)).into()
+        (f32::from(self) * f32::from(rhs)).into()
     }
 }
 
-impl Div for Z64 {
+impl Div for R16 {
     type Output = Self;
     fn div(self, rhs: Self) -> Self::Output {
-        i64::from(self).wrapping_div(i64::from(rhs)).into()
+        (f32::from(self) / f32::from(rhs)).into()
     }
 }
 
-impl Rem for Z64 {
+impl Rem for R16 {
     type Output = Self;
     fn rem(self, rhs: Self) -> Self::Output {
-        i64::from(self).wrapping_rem(i64::from(rhs)).into()
+        (f32::from(self) % f32::from(rhs)).into()
     }
 }
 
-impl Not for Z64 {
-    type Output = Self;
-    fn not(self) -> Self::Output {
-        (!i64::from(self)).into()
+impl PartialEq for R16 {
+    fn eq(&self, other: &R16) -> bool {
+        f32::from(*self) == f32::from(*other)
     }
 }
 
-impl Display for Z64 {
+impl Default for R16 {
+    fn default() -> Self {
+        R16 {
+            bits: [Bit::Zero; 16],
+        }
+    }
+}
+
+impl Display for R16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", i64::from(*self))
+        write!(f, "{}", f32::from(*self))
+    }
+}
+
+impl R16 {
+    /// Returns the value as `f32`; same as `f32::from(self)`.
+    pub fn to_f32(self) -> f32 {
+        f32::from(self)
     }
 }
 
 // --------------------- R32 ---------------------
 
 /// 32-bit floating-point number.
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// Equality and ordering follow IEEE-754 semantics like `f32`: `NaN` is
+/// unordered and `0.0 == -0.0`. Use `total_cmp` for a total order.
+#[derive(Debug, Clone, Copy)]
 pub struct R32 {
     bits: [Bit; 32],
 }
@@ -1815,16 +10530,134 @@ impl Rem for R32 {
     }
 }
 
+impl PartialEq for R32 {
+    fn eq(&self, other: &R32) -> bool {
+        f32::from(*self) == f32::from(*other)
+    }
+}
+
+impl PartialOrd for R32 {
+    fn partial_cmp(&self, other: &R32) -> Option<Ordering> {
+        f32::from(*self).partial_cmp(&f32::from(*other))
+    }
+}
+
+impl R32 {
+    /// Total ordering matching `f32::total_cmp`: negative `NaN` sorts first,
+    /// positive `NaN` last, and `-0.0` orders before `0.0`.
+    pub fn total_cmp(&self, other: &R32) -> Ordering {
+        f32::from(*self).total_cmp(&f32::from(*other))
+    }
+}
+
 impl Display for R32 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}", f32::from(*self))
     }
 }
 
+impl R32 {
+    /// Returns `Bool::True` if the value is `NaN`.
+    pub fn is_nan(&self) -> Bool {
+        Bool::new(f32::from(*self).is_nan())
+    }
+
+    /// Returns `Bool::True` if the value is positive or negative infinity.
+    pub fn is_infinite(&self) -> Bool {
+        Bool::new(f32::from(*self).is_infinite())
+    }
+
+    /// Returns `Bool::True` if the value is neither infinite nor `NaN`.
+    pub fn is_finite(&self) -> Bool {
+        Bool::new(f32::from(*self).is_finite())
+    }
+
+    /// Returns `Bool::True` if the sign bit is set, including `-0.0`.
+    pub fn is_sign_negative(&self) -> Bool {
+        Bool::new(f32::from(*self).is_sign_negative())
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(self) -> R32 {
+        f32::from(self).abs().into()
+    }
+
+    /// Returns the largest integer less than or equal to the value.
+    #[cfg(feature = "std")]
+    pub fn floor(self) -> R32 {
+        f32::from(self).floor().into()
+    }
+
+    /// Returns the smallest integer greater than or equal to the value.
+    #[cfg(feature = "std")]
+    pub fn ceil(self) -> R32 {
+        f32::from(self).ceil().into()
+    }
+
+    /// Rounds to the nearest integer, with halfway cases away from zero.
+    #[cfg(feature = "std")]
+    pub fn round(self) -> R32 {
+        f32::from(self).round().into()
+    }
+
+    /// Returns the integer part of the value.
+    #[cfg(feature = "std")]
+    pub fn trunc(self) -> R32 {
+        f32::from(self).trunc().into()
+    }
+
+    /// Returns the square root, or `NaN` for negative values.
+    #[cfg(feature = "std")]
+    pub fn sqrt(self) -> R32 {
+        f32::from(self).sqrt().into()
+    }
+}
+
+impl Neg for R32 {
+    type Output = Self;
+    /// Flips the sign bit directly, so zeros and `NaN` are negated as IEEE-754 expects.
+    fn neg(self) -> Self::Output {
+        let mut bits = self.bits;
+        bits[31] = !bits[31];
+        R32 { bits }
+    }
+}
+
+impl R32 {
+    /// Returns the sign bit (bit 31).
+    pub fn sign_bit(&self) -> Bit {
+        self.bits[31]
+    }
+
+    /// Returns the raw 8-bit biased exponent (bits 23–30).
+    pub fn exponent_bits(&self) -> N8 {
+        let mut bits = [Bit::Zero; 8];
+        bits.copy_from_slice(&self.bits[23..31]);
+        N8::new(bits)
+    }
+
+    /// Returns the raw 23-bit mantissa (bits 0–22), zero-extended to 32 bits.
+    pub fn mantissa_bits(&self) -> N32 {
+        let mut bits = [Bit::Zero; 32];
+        bits[..23].copy_from_slice(&self.bits[..23]);
+        N32::new(bits)
+    }
+}
+
+impl R32 {
+    /// Returns the value as `f32`; same as `f32::from(self)`.
+    pub fn to_f32(self) -> f32 {
+        f32::from(self)
+    }
+}
+
 // --------------------- R64 ---------------------
 
 /// 64-bit floating-point number.
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// Equality and ordering follow IEEE-754 semantics like `f64`: `NaN` is
+/// unordered and `0.0 == -0.0`. Use `total_cmp` for a total order.
+#[derive(Debug, Clone, Copy)]
 pub struct R64 {
     bits: [Bit; 64],
 }
@@ -1875,23 +10708,138 @@ impl Mul for R64 {
     }
 }
 
-impl Div for R64 {
+impl Div for R64 {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        (f64::from(self) / f64::from(rhs)).into()
+    }
+}
+
+impl Rem for R64 {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        (f64::from(self) % f64::from(rhs)).into()
+    }
+}
+
+impl PartialEq for R64 {
+    fn eq(&self, other: &R64) -> bool {
+        f64::from(*self) == f64::from(*other)
+    }
+}
+
+impl PartialOrd for R64 {
+    fn partial_cmp(&self, other: &R64) -> Option<Ordering> {
+        f64::from(*self).partial_cmp(&f64::from(*other))
+    }
+}
+
+impl R64 {
+    /// Total ordering matching `f64::total_cmp`: negative `NaN` sorts first,
+    /// positive `NaN` last, and `-0.0` orders before `0.0`.
+    pub fn total_cmp(&self, other: &R64) -> Ordering {
+        f64::from(*self).total_cmp(&f64::from(*other))
+    }
+}
+
+impl Display for R64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", f64::from(*self))
+    }
+}
+
+impl R64 {
+    /// Returns `Bool::True` if the value is `NaN`.
+    pub fn is_nan(&self) -> Bool {
+        Bool::new(f64::from(*self).is_nan())
+    }
+
+    /// Returns `Bool::True` if the value is positive or negative infinity.
+    pub fn is_infinite(&self) -> Bool {
+        Bool::new(f64::from(*self).is_infinite())
+    }
+
+    /// Returns `Bool::True` if the value is neither infinite nor `NaN`.
+    pub fn is_finite(&self) -> Bool {
+        Bool::new(f64::from(*self).is_finite())
+    }
+
+    /// Returns `Bool::True` if the sign bit is set, including `-0.0`.
+    pub fn is_sign_negative(&self) -> Bool {
+        Bool::new(f64::from(*self).is_sign_negative())
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(self) -> R64 {
+        f64::from(self).abs().into()
+    }
+
+    /// Returns the largest integer less than or equal to the value.
+    #[cfg(feature = "std")]
+    pub fn floor(self) -> R64 {
+        f64::from(self).floor().into()
+    }
+
+    /// Returns the smallest integer greater than or equal to the value.
+    #[cfg(feature = "std")]
+    pub fn ceil(self) -> R64 {
+        f64::from(self).ceil().into()
+    }
+
+    /// Rounds to the nearest integer, with halfway cases away from zero.
+    #[cfg(feature = "std")]
+    pub fn round(self) -> R64 {
+        f64::from(self).round().into()
+    }
+
+    /// Returns the integer part of the value.
+    #[cfg(feature = "std")]
+    pub fn trunc(self) -> R64 {
+        f64::from(self).trunc().into()
+    }
+
+    /// Returns the square root, or `NaN` for negative values.
+    #[cfg(feature = "std")]
+    pub fn sqrt(self) -> R64 {
+        f64::from(self).sqrt().into()
+    }
+}
+
+impl Neg for R64 {
     type Output = Self;
-    fn div(self, rhs: Self) -> Self::Output {
-        (f64::from(self) / f64::from(rhs)).into()
+    /// Flips the sign bit directly, so zeros and `NaN` are negated as IEEE-754 expects.
+    fn neg(self) -> Self::Output {
+        let mut bits = self.bits;
+        bits[63] = !bits[63];
+        R64 { bits }
     }
 }
 
-impl Rem for R64 {
-    type Output = Self;
-    fn rem(self, rhs: Self) -> Self::Output {
-        (f64::from(self) % f64::from(rhs)).into()
+impl R64 {
+    /// Returns the sign bit (bit 63).
+    pub fn sign_bit(&self) -> Bit {
+        self.bits[63]
+    }
+
+    /// Returns the raw 11-bit biased exponent (bits 52–62), zero-extended to 16 bits.
+    pub fn exponent_bits(&self) -> N16 {
+        let mut bits = [Bit::Zero; 16];
+        bits[..11].copy_from_slice(&self.bits[52..63]);
+        N16::new(bits)
+    }
+
+    /// Returns the raw 52-bit mantissa (bits 0–51), zero-extended to 64 bits.
+    pub fn mantissa_bits(&self) -> N64 {
+        let mut bits = [Bit::Zero; 64];
+        bits[..52].copy_from_slice(&self.bits[..52]);
+        N64 { bits }
     }
 }
 
-impl Display for R64 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", f64::from(*self))
+impl R64 {
+    /// Returns the value as `f64`; same as `f64::from(self)`.
+    pub fn to_f64(self) -> f64 {
+        f64::from(self)
     }
 }
 
@@ -1959,18 +10907,24 @@ where
     }
 }
 
+/// Shifts along the bit array; shifting by 32 or more yields zero.
 impl Shl<u8> for Z32 {
     type Output = Self;
     fn shl(self, rhs: u8) -> Self::Output {
-        (i32::from(self) << rhs).into()
+        let mut bits = [Bit::Zero; 32];
+        let shift = rhs as usize;
+        if shift >= 32 {
+            return Z32 { bits };
+        }
+        bits[shift..].copy_from_slice(&self.bits[..32 - shift]);
+        Z32 { bits }
     }
 }
 
 // Implement From with generic type
 impl From<Z32> for FixedPoint<Z32, 16> {
     fn from(value: Z32) -> Self {
-        let as_z32 = Z32::from(value);
-        let scaled_val = as_z32 << 16;
+        let scaled_val = value << 16;
         Self {
             internal: scaled_val,
         }
@@ -1985,22 +10939,30 @@ impl Default for Z32 {
     }
 }
 
+/// Arithmetic shift: the sign bit is replicated into the vacated positions.
+/// Shifting by 32 or more fills every bit with the sign bit.
 impl Shr<u8> for Z32 {
     type Output = Self;
     fn shr(self, rhs: u8) -> Self::Output {
-        (i32::from(self) << rhs).into()
+        let sign = self.bits[31];
+        let mut bits = [sign; 32];
+        let shift = rhs as usize;
+        if shift >= 32 {
+            return Z32 { bits };
+        }
+        bits[..32 - shift].copy_from_slice(&self.bits[shift..]);
+        Z32 { bits }
     }
 }
 
-impl From<Z32> for Z64 {
-    fn from(value: Z32) -> Self {
-        Z64::try_from(value).unwrap()
-    }
-}
+/// Fails if the value is outside the `i32` range.
+impl TryFrom<Z64> for Z32 {
+    type Error = ConversionError;
 
-impl From<Z64> for Z32 {
-    fn from(value: Z64) -> Self {
-        Z32::try_from(value).unwrap()
+    fn try_from(value: Z64) -> Result<Self, Self::Error> {
+        i32::try_from(i64::from(value))
+            .map(Into::into)
+            .map_err(|_| ConversionError)
     }
 }
 
@@ -2030,9 +10992,7 @@ impl Default for Z16 {
 
 impl Default for N8 {
     fn default() -> Self {
-        N8 {
-            bits: [Bit::Zero; 8],
-        }
+        N8::ZERO
     }
 }
 
@@ -2060,6 +11020,22 @@ impl Default for N64 {
     }
 }
 
+impl Default for N128 {
+    fn default() -> Self {
+        N128 {
+            bits: [Bit::Zero; 128],
+        }
+    }
+}
+
+impl Default for Z128 {
+    fn default() -> Self {
+        Z128 {
+            bits: [Bit::Zero; 128],
+        }
+    }
+}
+
 impl Default for R32 {
     fn default() -> Self {
         R32 {
@@ -2076,6 +11052,28 @@ impl Default for R64 {
     }
 }
 
+impl Default for Byte {
+    fn default() -> Self {
+        Byte {
+            bits: [Bit::Zero; 8],
+        }
+    }
+}
+
+impl Default for Nibble {
+    fn default() -> Self {
+        Nibble::ZERO
+    }
+}
+
+impl Default for Word {
+    fn default() -> Self {
+        Word {
+            bits: [Bit::Zero; 16],
+        }
+    }
+}
+
 impl<T, const FB: u8> Default for FixedPoint<T, FB>
 where
     T: Default,
@@ -2087,6 +11085,425 @@ where
     }
 }
 
+// --------------------- serde ---------------------
+
+/// `Serialize`/`Deserialize` using the compact primitive representation, so an
+/// `N8` is written as a number from 0 to 255 rather than an array of bits.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    impl Serialize for Bit {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            u8::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Bit {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            match u8::deserialize(deserializer)? {
+                0 => Ok(Bit::Zero),
+                1 => Ok(Bit::One),
+                value => Err(D::Error::custom(format_args!(
+                    "bit out of range: {}",
+                    value
+                ))),
+            }
+        }
+    }
+
+    impl Serialize for Bool {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.to_bool().serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Bool {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            bool::deserialize(deserializer).map(Bool::from)
+        }
+    }
+
+    impl Serialize for Byte {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            u8::from(N8::from(*self)).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Byte {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u8::deserialize(deserializer).map(|value| Byte::from(N8::from(value)))
+        }
+    }
+
+    impl Serialize for Nibble {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            u8::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Nibble {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            match u8::deserialize(deserializer)? {
+                value @ 0..=15 => Ok(Nibble::from(value)),
+                value => Err(D::Error::custom(format_args!(
+                    "nibble out of range: {}",
+                    value
+                ))),
+            }
+        }
+    }
+
+    impl Serialize for N8 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            u8::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for N8 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u8::deserialize(deserializer).map(N8::from)
+        }
+    }
+
+    impl Serialize for N16 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            u16::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for N16 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u16::deserialize(deserializer).map(N16::from)
+        }
+    }
+
+    impl Serialize for N32 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            u32::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for N32 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u32::deserialize(deserializer).map(N32::from)
+        }
+    }
+
+    impl Serialize for N64 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            u64::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for N64 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u64::deserialize(deserializer).map(N64::from)
+        }
+    }
+
+    impl Serialize for N128 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            u128::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for N128 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u128::deserialize(deserializer).map(N128::from)
+        }
+    }
+
+    impl Serialize for Z8 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            i8::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Z8 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            i8::deserialize(deserializer).map(Z8::from)
+        }
+    }
+
+    impl Serialize for Z16 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            i16::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Z16 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            i16::deserialize(deserializer).map(Z16::from)
+        }
+    }
+
+    impl Serialize for Z32 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            i32::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Z32 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            i32::deserialize(deserializer).map(Z32::from)
+        }
+    }
+
+    impl Serialize for Z64 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            i64::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Z64 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            i64::deserialize(deserializer).map(Z64::from)
+        }
+    }
+
+    impl Serialize for Z128 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            i128::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Z128 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            i128::deserialize(deserializer).map(Z128::from)
+        }
+    }
+
+    impl Serialize for R32 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            f32::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for R32 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            f32::deserialize(deserializer).map(R32::from)
+        }
+    }
+
+    impl Serialize for R64 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            f64::from(*self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for R64 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            f64::deserialize(deserializer).map(R64::from)
+        }
+    }
+}
+
+// --------------------- rand ---------------------
+
+/// `Standard` sampling for use with `rand::random` and `Rng::gen`. Integers are
+/// uniform over their whole range; floats mirror the primitive `[0, 1)` range.
+#[cfg(feature = "rand")]
+mod rand_impls {
+    use super::*;
+    use rand::{
+        Rng,
+        distributions::{Distribution, Standard},
+    };
+
+    impl Distribution<N8> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> N8 {
+            N8::from(rng.r#gen::<u8>())
+        }
+    }
+
+    impl Distribution<N16> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> N16 {
+            N16::from(rng.r#gen::<u16>())
+        }
+    }
+
+    impl Distribution<N32> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> N32 {
+            N32::from(rng.r#gen::<u32>())
+        }
+    }
+
+    impl Distribution<N64> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> N64 {
+            N64::from(rng.r#gen::<u64>())
+        }
+    }
+
+    impl Distribution<N128> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> N128 {
+            N128::from(rng.r#gen::<u128>())
+        }
+    }
+
+    impl Distribution<Z8> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Z8 {
+            Z8::from(rng.r#gen::<i8>())
+        }
+    }
+
+    impl Distribution<Z16> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Z16 {
+            Z16::from(rng.r#gen::<i16>())
+        }
+    }
+
+    impl Distribution<Z32> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Z32 {
+            Z32::from(rng.r#gen::<i32>())
+        }
+    }
+
+    impl Distribution<Z64> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Z64 {
+            Z64::from(rng.r#gen::<i64>())
+        }
+    }
+
+    impl Distribution<Z128> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Z128 {
+            Z128::from(rng.r#gen::<i128>())
+        }
+    }
+
+    /// Uniform in `[0, 1)` in steps of `2^-11`, all exactly representable in binary16.
+    impl Distribution<R16> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> R16 {
+            R16::from((rng.r#gen::<u16>() >> 5) as f32 / 2048.0)
+        }
+    }
+
+    /// Same as `f32`'s `Standard`: uniform in `[0, 1)`.
+    impl Distribution<R32> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> R32 {
+            R32::from(rng.r#gen::<f32>())
+        }
+    }
+
+    /// Same as `f64`'s `Standard`: uniform in `[0, 1)`.
+    impl Distribution<R64> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> R64 {
+            R64::from(rng.r#gen::<f64>())
+        }
+    }
+}
+
+// --------------------- arbitrary ---------------------
+
+/// `Arbitrary` for fuzzing and property tests: each integer type consumes the
+/// same bytes as its primitive and converts from it.
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use super::*;
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    impl<'a> Arbitrary<'a> for N8 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(N8::from(u8::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            u8::size_hint(depth)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for N16 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(N16::from(u16::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            u16::size_hint(depth)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for N32 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(N32::from(u32::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            u32::size_hint(depth)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for N64 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(N64::from(u64::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            u64::size_hint(depth)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for N128 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(N128::from(u128::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            u128::size_hint(depth)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Z8 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Z8::from(i8::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            i8::size_hint(depth)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Z16 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Z16::from(i16::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            i16::size_hint(depth)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Z32 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Z32::from(i32::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            i32::size_hint(depth)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Z64 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Z64::from(i64::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            i64::size_hint(depth)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Z128 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Z128::from(i128::arbitrary(u)?))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            i128::size_hint(depth)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2094,9 +11511,9 @@ mod tests {
     #[test]
 
     fn test_bit_enum() {
-        assert_eq!(Bit::Zero.as_bool() == Bool::False, true);
+        assert!(Bit::Zero.as_bool() == Bool::False);
 
-        assert_eq!(Bit::One.as_bool() == Bool::True, true);
+        assert!(Bit::One.as_bool() == Bool::True);
 
         assert_eq!(Bit::from_bool(Bool::False), Bit::Zero);
 
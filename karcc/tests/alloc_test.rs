@@ -0,0 +1,106 @@
+#![cfg(feature = "alloc")]
+
+use karcc::*;
+
+#[test]
+fn test_to_grouped_binary() {
+    let n = N32::from(0xAC0F_F0A5);
+    assert_eq!(
+        n.to_grouped_binary(4, '_'),
+        "1010_1100_0000_1111_1111_0000_1010_0101"
+    );
+    assert_eq!(
+        n.to_grouped_binary(8, ' '),
+        "10101100 00001111 11110000 10100101"
+    );
+    assert_eq!(
+        N8::from(0b1010_1100).to_grouped_binary(3, '_'),
+        "10_101_100"
+    );
+    assert_eq!(N8::from(5).to_grouped_binary(0, '_'), "00000101");
+    assert_eq!(Z16::from(-1).to_grouped_binary(8, ':'), "11111111:11111111");
+}
+
+#[test]
+fn test_bits_from_str_and_accessors() {
+    let mut bits: Bits = "1011".parse().unwrap();
+    assert_eq!(bits.len(), 4);
+    assert_eq!(bits.get(0), Some(Bit::One));
+    assert_eq!(bits.get(2), Some(Bit::Zero));
+    assert_eq!(bits.get(4), None);
+    bits.push(Bit::One);
+    assert_eq!(bits.to_string(), "11011");
+    assert_eq!(bits.pop(), Some(Bit::One));
+    bits.set(2, Bit::One);
+    assert_eq!(format!("{}", bits), "1111");
+    assert_eq!(bits.iter().filter(|&b| b == Bit::One).count(), 4);
+    assert!(Bits::new().is_empty());
+    assert_eq!(
+        "10a".parse::<Bits>(),
+        Err(ParseBitsError::InvalidChar { ch: 'a', index: 2 })
+    );
+}
+
+#[test]
+fn test_bits_bitwise_ops() {
+    let a: Bits = "1100".parse().unwrap();
+    let b: Bits = "1010".parse().unwrap();
+    assert_eq!((&a ^ &b).to_string(), "0110");
+    assert_eq!((&a & &b).to_string(), "1000");
+    assert_eq!((a | b).to_string(), "1110");
+    let short: Bits = "1".parse().unwrap();
+    let long: Bits = "110".parse().unwrap();
+    assert_eq!((short ^ long).to_string(), "111");
+}
+
+#[test]
+fn test_bits_conversions() {
+    let bits: Bits = "10101010".parse().unwrap();
+    assert_eq!(N8::try_from(bits.clone()), Ok(N8::from(0b1010_1010)));
+    assert_eq!(Byte::try_from(bits).unwrap().value(), 0b1010_1010);
+    assert_eq!(
+        N16::try_from(Bits::from(N16::from(0xBEEF))),
+        Ok(N16::from(0xBEEF))
+    );
+    assert_eq!(Bits::from(N8::from(5)).len(), 8);
+    assert_eq!(Bits::from(Byte::from(N8::from(5))), Bits::from(N8::from(5)));
+    let wide: Bits = "100000000".parse().unwrap();
+    assert!(N8::try_from(wide).is_err());
+    let short: Bits = "11".parse().unwrap();
+    assert_eq!(N16::try_from(short), Ok(N16::from(3)));
+    let collected: Bits = N8::from(6).into_iter().collect();
+    assert_eq!(
+        collected.into_iter().take(3).collect::<Vec<_>>(),
+        [Bit::Zero, Bit::One, Bit::One]
+    );
+}
+
+#[test]
+fn test_nuint_formatting_and_ordering() {
+    assert_eq!(format!("{}", Nuint::<12>::MAX), "4095");
+    assert_eq!(format!("{:#b}", Nuint::<12>::from(5)), "0b101");
+    assert_eq!(format!("{}", Nuint::<3>::ZERO), "0");
+    let wide = Nuint::<200>::MAX;
+    assert_eq!(
+        format!("{}", wide),
+        "1606938044258990275541962092341162602522202993782792835301375"
+    );
+    assert!(u128::try_from(wide).is_err());
+    assert!(Nuint::<12>::from(2048) > Nuint::from(2047));
+    assert_eq!(wide.count_ones(), 200);
+}
+
+#[test]
+fn test_ripple_add() {
+    let a = [Bit::One, Bit::One, Bit::One];
+    let b = [Bit::One];
+    assert_eq!(
+        ripple_add(&a, &b),
+        (vec![Bit::Zero, Bit::Zero, Bit::Zero], Bit::One)
+    );
+    let (sum, carry) = ripple_add(&[Bit::Zero, Bit::One], &[Bit::One, Bit::Zero, Bit::One]);
+    assert_eq!(
+        (sum, carry),
+        (vec![Bit::One, Bit::One, Bit::One], Bit::Zero)
+    );
+}
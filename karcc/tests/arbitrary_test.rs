@@ -0,0 +1,24 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use karcc::*;
+
+#[test]
+fn test_arbitrary_reads_primitive_bytes() {
+    let data = [0x34, 0x12, 0xFE, 0xFF, 0xFF, 0xFF, 0x80];
+    let mut u = Unstructured::new(&data);
+    assert_eq!(N16::arbitrary(&mut u).unwrap(), N16::from(0x1234));
+    assert_eq!(Z32::arbitrary(&mut u).unwrap(), Z32::from(-2));
+    assert_eq!(N8::arbitrary(&mut u).unwrap(), N8::from(0x80));
+    assert_eq!(N16::size_hint(0), u16::size_hint(0));
+}
+
+#[test]
+fn test_arbitrary_tuple_of_types() {
+    let data: Vec<u8> = (0..64).collect();
+    let mut u = Unstructured::new(&data);
+    let (a, b, c): (N64, Z128, Z8) = Arbitrary::arbitrary(&mut u).unwrap();
+    assert_eq!(a, N64::from(u64::from_le_bytes([0, 1, 2, 3, 4, 5, 6, 7])));
+    assert_eq!(b.to_le_bytes()[0], 8);
+    assert_eq!(i8::from(c), 24);
+}
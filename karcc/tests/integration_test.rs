@@ -32,6 +32,16 @@ fn test_n8_mul() {
     assert_eq!(u8::from(result), 50);
 }
 
+#[test]
+fn test_n8_mul_matches_wrapping_mul() {
+    for a in 0..=255u8 {
+        for b in 0..=255u8 {
+            let result = N8::from(a) * N8::from(b);
+            assert_eq!(u8::from(result), a.wrapping_mul(b));
+        }
+    }
+}
+
 #[test]
 fn test_n8_div() {
     let a = N8::from(20);
@@ -40,6 +50,45 @@ fn test_n8_div() {
     assert_eq!(u8::from(result), 2);
 }
 
+#[test]
+fn test_n8_div_rem_matches_primitive() {
+    let dividends = [0u8, 1, 5, 7, 20, 100, 128, 200, 254, 255];
+    let divisors = [1u8, 2, 3, 5, 7, 10, 16, 127, 128, 255];
+    for &a in &dividends {
+        for &b in &divisors {
+            assert_eq!(u8::from(N8::from(a) / N8::from(b)), a / b);
+            assert_eq!(u8::from(N8::from(a) % N8::from(b)), a % b);
+        }
+    }
+    assert_eq!(u8::from(N8::from(0) / N8::from(5)), 0);
+    assert_eq!(u8::from(N8::from(255) / N8::from(7)), 36);
+    assert_eq!(u8::from(N8::from(255) % N8::from(7)), 3);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_n8_div_by_zero() {
+    let _ = N8::from(10) / N8::ZERO;
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_n8_rem_by_zero() {
+    let _ = N8::from(10) % N8::ZERO;
+}
+
+#[test]
+fn test_n8_rem() {
+    let a = N8::from(20);
+    let b = N8::from(3);
+    let result = a % b;
+    assert_eq!(u8::from(result), 2);
+
+    let mut c = N8::from(255);
+    c %= N8::from(16);
+    assert_eq!(u8::from(c), 15);
+}
+
 #[test]
 fn test_n8_count_ones() {
     let n = N8::from(0b1101_0101);
@@ -92,6 +141,27 @@ fn test_n16_mul() {
     assert_eq!(u32::from(result), 5000);
 }
 
+#[test]
+fn test_n16_mul_widens() {
+    let result = N16::from(u16::MAX) * N16::from(u16::MAX);
+    assert_eq!(u32::from(result), 65535 * 65535);
+}
+
+#[test]
+fn test_n16_wrapping_mul() {
+    let result = N16::from(300).wrapping_mul(N16::from(300));
+    assert_eq!(u16::from(result), 300u16.wrapping_mul(300));
+    assert_eq!(N16::from(100).wrapping_mul(N16::from(50)), N16::from(5000));
+}
+
+#[test]
+fn test_n16_rem() {
+    let a = N16::from(2000);
+    let b = N16::from(300);
+    assert_eq!(u16::from(a % b), 200);
+    assert_eq!(u16::from(N16::from(u16::MAX) % N16::from(256)), 255);
+}
+
 #[test]
 fn test_n16_div() {
     let a = N16::from(200);
@@ -195,6 +265,14 @@ fn test_n64_div() {
     assert_eq!(u64::from(result), 20000);
 }
 
+#[test]
+fn test_n64_count_ones() {
+    let n = N64::from(0xFFFF_FFFF_0000_0000);
+    assert_eq!(n.count_ones(), 32);
+    assert_eq!(n.count_zeros(), 32);
+    assert_eq!(N64::from(0).count_zeros(), 64);
+}
+
 #[test]
 fn test_z8_from_i8_and_back() {
     for i in -128..=127 {
@@ -501,4 +579,2252 @@ fn test_r64_rem() {
     let b = R64::from(2.0);
     let result = a % b;
     assert_eq!(f64::from(result), 1.0);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_fixed_point_from_z32_scales() {
+    assert_eq!(
+        FixedPoint::<Z32, 16>::from(Z32::from(3)),
+        FixedPoint::new(Z32::from(3 << 16))
+    );
+}
+
+#[test]
+fn test_z32_shr_shifts_right() {
+    assert_eq!(Z32::from(256) >> 4, Z32::from(16));
+    assert_eq!(Z32::from(-8) >> 1, Z32::from(-4));
+}
+
+#[test]
+fn test_z32_to_z64_widening() {
+    assert_eq!(Z64::from(Z32::from(-5)), Z64::from(-5));
+    assert_eq!(Z64::from(Z32::from(i32::MIN)), Z64::from(i32::MIN as i64));
+    assert_eq!(Z64::from(Z32::from(i32::MAX)), Z64::from(i32::MAX as i64));
+}
+
+#[test]
+fn test_n8_checked_add() {
+    assert_eq!(N8::from(200).checked_add(N8::from(100)), None);
+    assert_eq!(N8::from(200).checked_add(N8::from(55)), Some(N8::from(255)));
+}
+
+#[test]
+fn test_n8_checked_sub() {
+    assert_eq!(N8::from(0).checked_sub(N8::from(1)), None);
+    assert_eq!(N8::from(10).checked_sub(N8::from(10)), Some(N8::from(0)));
+}
+
+#[test]
+fn test_n8_checked_mul() {
+    assert_eq!(N8::from(16).checked_mul(N8::from(16)), None);
+    assert_eq!(N8::from(15).checked_mul(N8::from(17)), Some(N8::from(255)));
+}
+
+#[test]
+fn test_wide_unsigned_checked_arithmetic() {
+    assert_eq!(N16::from(u16::MAX).checked_add(N16::from(1)), None);
+    assert_eq!(
+        N16::from(1000).checked_add(N16::from(24)),
+        Some(N16::from(1024))
+    );
+    assert_eq!(N32::from(0).checked_sub(N32::from(1)), None);
+    assert_eq!(N32::from(7).checked_sub(N32::from(2)), Some(N32::from(5)));
+    assert_eq!(N64::from(u64::MAX).checked_mul(N64::from(2)), None);
+    assert_eq!(N64::from(3).checked_mul(N64::from(4)), Some(N64::from(12)));
+}
+
+#[test]
+fn test_n8_overflowing_add() {
+    assert_eq!(
+        N8::from(250).overflowing_add(N8::from(10)),
+        (N8::from(4), Bool::True)
+    );
+    assert_eq!(
+        N8::from(250).overflowing_add(N8::from(5)),
+        (N8::from(255), Bool::False)
+    );
+}
+
+#[test]
+fn test_wide_unsigned_overflowing_add() {
+    assert_eq!(
+        N16::from(u16::MAX).overflowing_add(N16::from(2)),
+        (N16::from(1), Bool::True)
+    );
+    assert_eq!(
+        N32::from(1).overflowing_add(N32::from(2)),
+        (N32::from(3), Bool::False)
+    );
+    assert_eq!(
+        N64::from(u64::MAX).overflowing_add(N64::from(1)),
+        (N64::from(0), Bool::True)
+    );
+}
+
+#[test]
+fn test_unsigned_saturating_arithmetic() {
+    assert_eq!(N8::from(250).saturating_add(N8::from(10)), N8::from(255));
+    assert_eq!(N8::from(5).saturating_sub(N8::from(10)), N8::ZERO);
+    assert_eq!(N8::from(5).saturating_add(N8::from(10)), N8::from(15));
+    assert_eq!(N16::from(1).saturating_sub(N16::from(2)), N16::from(0));
+    assert_eq!(
+        N32::from(u32::MAX).saturating_add(N32::from(1)),
+        N32::from(u32::MAX)
+    );
+    assert_eq!(
+        N64::from(u64::MAX).saturating_add(N64::from(9)),
+        N64::from(u64::MAX)
+    );
+}
+
+#[test]
+fn test_signed_saturating_arithmetic() {
+    assert_eq!(Z8::from(-120).saturating_sub(Z8::from(50)), Z8::from(-128));
+    assert_eq!(Z8::from(120).saturating_add(Z8::from(50)), Z8::from(127));
+    assert_eq!(Z8::from(-20).saturating_add(Z8::from(50)), Z8::from(30));
+    assert_eq!(
+        Z16::from(i16::MIN).saturating_sub(Z16::from(1)),
+        Z16::from(i16::MIN)
+    );
+    assert_eq!(
+        Z32::from(i32::MAX).saturating_add(Z32::from(1)),
+        Z32::from(i32::MAX)
+    );
+    assert_eq!(
+        Z64::from(i64::MIN).saturating_add(Z64::from(-1)),
+        Z64::from(i64::MIN)
+    );
+}
+
+#[test]
+fn test_word_from_u16_and_back() {
+    for i in [0u16, 1, 0x1234, 0x8000, u16::MAX] {
+        let w = Word::from(i);
+        assert_eq!(u16::from(w), i);
+    }
+}
+
+#[test]
+fn test_word_bit_access() {
+    let mut w = Word::new([Bit::Zero; 16]);
+    w.set_bit(15, Bit::One);
+    assert_eq!(w.get_bit(15), Bit::One);
+    assert_eq!(u16::from(w), 0x8000);
+    w.invert();
+    assert_eq!(u16::from(w), 0x7FFF);
+}
+
+#[test]
+fn test_word_display() {
+    let w = Word::from(0b1010_0000_0000_0011);
+    assert_eq!(format!("{}", w), "1010000000000011");
+    assert_eq!(format!("{:b}", w), "1010000000000011");
+}
+
+#[test]
+fn test_word_bitwise_and_shifts() {
+    let a = Word::from(0xFF00);
+    let b = Word::from(0x0FF0);
+    assert_eq!(u16::from(a & b), 0x0F00);
+    assert_eq!(u16::from(a | b), 0xFFF0);
+    assert_eq!(u16::from(a ^ b), 0xF0F0);
+    assert_eq!(u16::from(!a), 0x00FF);
+    assert_eq!(u16::from(a << 4), 0xF000);
+    assert_eq!(u16::from(a >> 4), 0x0FF0);
+    assert_eq!(u16::from(a << 16), 0);
+}
+
+#[test]
+fn test_z8_neg() {
+    assert_eq!(i8::from(-Z8::from(10)), -10);
+    assert_eq!(i8::from(-Z8::from(-10)), 10);
+    assert_eq!(i8::from(-Z8::from(-128)), -128);
+}
+
+#[test]
+fn test_wide_signed_neg() {
+    assert_eq!(i16::from(-Z16::from(1000)), -1000);
+    assert_eq!(i16::from(-Z16::from(i16::MIN)), i16::MIN);
+    assert_eq!(i32::from(-Z32::from(-100000)), 100000);
+    assert_eq!(i32::from(-Z32::from(i32::MIN)), i32::MIN);
+    assert_eq!(i64::from(-Z64::from(1000000000)), -1000000000);
+    assert_eq!(i64::from(-Z64::from(i64::MIN)), i64::MIN);
+}
+
+#[test]
+fn test_wide_unsigned_rotate() {
+    let mut a = N16::from(0x8001);
+    a.rotate_left(1);
+    assert_eq!(u16::from(a), 0x8001u16.rotate_left(1));
+
+    let mut b = N32::from(0x1234_5678);
+    b.rotate_left(12);
+    assert_eq!(u32::from(b), 0x1234_5678u32.rotate_left(12));
+    b.rotate_right(12);
+    assert_eq!(u32::from(b), 0x1234_5678);
+
+    let mut c = N64::from(0xDEAD_BEEF_0000_0001);
+    c.rotate_right(4);
+    assert_eq!(u64::from(c), 0xDEAD_BEEF_0000_0001u64.rotate_right(4));
+}
+
+#[test]
+fn test_wide_unsigned_reverse_bits() {
+    let mut a = N32::from(0x1234_5678);
+    a.reverse_bits();
+    assert_eq!(u32::from(a), 0x1234_5678u32.reverse_bits());
+
+    let mut b = N64::from(0xDEAD_BEEF_0000_0001);
+    b.reverse_bits();
+    assert_eq!(u64::from(b), 0xDEAD_BEEF_0000_0001u64.reverse_bits());
+}
+
+fn rotate_twice<T: BitwiseRotate>(value: &mut T) {
+    value.rotate_left(1);
+    value.rotate_left(1);
+}
+
+#[test]
+fn test_bitwise_rotate_generic() {
+    let mut a = N8::from(0b0000_0001);
+    let mut b = N64::from(1);
+    rotate_twice(&mut a);
+    rotate_twice(&mut b);
+    assert_eq!(u8::from(a), 4);
+    assert_eq!(u64::from(b), 4);
+}
+
+#[test]
+fn test_unsigned_hex_formatting() {
+    assert_eq!(format!("{:02x}", N8::from(255)), "ff");
+    assert_eq!(format!("{:02X}", N8::from(10)), "0A");
+    assert_eq!(format!("{:#06x}", N16::from(0x0abc)), "0x0abc");
+    assert_eq!(format!("{:08X}", N32::from(0x00C0_FFEE)), "00C0FFEE");
+    assert_eq!(format!("{:x}", N64::from(0xDEAD_BEEF)), "deadbeef");
+}
+
+#[test]
+fn test_signed_hex_formatting() {
+    assert_eq!(format!("{:x}", Z8::from(-1)), format!("{:x}", -1i8));
+    assert_eq!(format!("{:X}", Z16::from(-2)), "FFFE");
+    assert_eq!(format!("{:04x}", Z32::from(0x2a)), "002a");
+    assert_eq!(format!("{:x}", Z64::from(i64::MIN)), "8000000000000000");
+}
+
+#[test]
+fn test_unsigned_binary_and_octal_formatting() {
+    assert_eq!(format!("{:b}", N8::from(5)), "101");
+    assert_eq!(format!("{:#b}", N8::from(5)), "0b101");
+    assert_eq!(format!("{:#010b}", N8::from(5)), "0b00000101");
+    assert_eq!(format!("{:016b}", N16::from(0x00F0)), "0000000011110000");
+    assert_eq!(format!("{:o}", N32::from(8)), "10");
+    assert_eq!(format!("{:#o}", N64::from(64)), "0o100");
+    assert_eq!(format!("{:>6o}", N8::from(9)), "    11");
+}
+
+#[test]
+fn test_byte_binary_formatting_flags() {
+    let byte = Byte::new([
+        Bit::One,
+        Bit::Zero,
+        Bit::One,
+        Bit::Zero,
+        Bit::Zero,
+        Bit::Zero,
+        Bit::Zero,
+        Bit::Zero,
+    ]);
+    assert_eq!(format!("{:#010b}", byte), "0b00000101");
+    assert_eq!(format!("{:08b}", byte), "00000101");
+    assert_eq!(format!("{:b}", byte), "00000101");
+    assert_eq!(format!("{:#b}", byte), "0b00000101");
+    assert_eq!(format!("{:>10b}", byte), "  00000101");
+}
+
+#[test]
+fn test_word_binary_keeps_full_width() {
+    let w = Word::from(0x0003);
+    assert_eq!(format!("{:b}", w), "0000000000000011");
+    assert_eq!(format!("{:#b}", w), "0b0000000000000011");
+    assert_eq!(format!("{}", w), format!("{:b}", w));
+}
+
+#[test]
+fn test_wide_unsigned_from_str() {
+    assert_eq!("1010101010101010".parse::<N16>(), Ok(N16::from(0xAAAA)));
+    assert_eq!("101".parse::<N32>(), Ok(N32::from(5)));
+    assert!("10000000000000000".parse::<N16>().is_err());
+    assert!("102".parse::<N64>().is_err());
+}
+
+#[test]
+fn test_wide_unsigned_from_str_round_trip() {
+    for value in [0u16, 1, 0x1234, u16::MAX] {
+        let n = N16::from(value);
+        assert_eq!(format!("{:b}", n).parse::<N16>(), Ok(n));
+    }
+    for value in [0u32, 7, 0xDEAD_BEEF, u32::MAX] {
+        let n = N32::from(value);
+        assert_eq!(format!("{:b}", n).parse::<N32>(), Ok(n));
+    }
+    for value in [0u64, 42, 0x0123_4567_89AB_CDEF, u64::MAX] {
+        let n = N64::from(value);
+        assert_eq!(format!("{:b}", n).parse::<N64>(), Ok(n));
+    }
+}
+
+#[test]
+fn test_parse_bits_error() {
+    assert_eq!(
+        "101010101".parse::<N8>(),
+        Err(ParseBitsError::TooLong { len: 9 })
+    );
+    assert_eq!(
+        "10x0".parse::<N8>(),
+        Err(ParseBitsError::InvalidChar { ch: 'x', index: 2 })
+    );
+    assert_eq!(
+        "10x0".parse::<N32>(),
+        Err(ParseBitsError::InvalidChar { ch: 'x', index: 2 })
+    );
+    assert_eq!("1010".parse::<N8>(), Ok(N8::from(10)));
+}
+
+#[test]
+fn test_parse_bits_error_display() {
+    let err = "10x0".parse::<N16>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid digit 'x' at index 2");
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert!(err.to_string().contains("index 2"));
+}
+
+#[test]
+fn test_unsigned_widening_conversions() {
+    assert_eq!(N16::from(N8::from(200)), N16::from(200));
+    assert_eq!(N32::from(N8::from(255)), N32::from(255));
+    assert_eq!(N32::from(N16::from(0xBEEF)), N32::from(0xBEEF));
+    assert_eq!(N64::from(N8::from(1)), N64::from(1));
+    assert_eq!(N64::from(N16::from(u16::MAX)), N64::from(0xFFFF));
+    assert_eq!(N64::from(N32::from(u32::MAX)), N64::from(0xFFFF_FFFF));
+}
+
+#[test]
+fn test_unsigned_narrowing_conversions() {
+    assert_eq!(N8::try_from(N16::from(255)), Ok(N8::from(255)));
+    assert_eq!(N8::try_from(N16::from(256)), Err(ConversionError));
+    assert_eq!(N16::try_from(N32::from(0xFFFF)), Ok(N16::from(0xFFFF)));
+    assert_eq!(N16::try_from(N32::from(0x1_0000)), Err(ConversionError));
+    assert_eq!(N32::try_from(N64::from(42)), Ok(N32::from(42)));
+    assert_eq!(N32::try_from(N64::from(1 << 40)), Err(ConversionError));
+}
+
+#[test]
+fn test_signed_min_max_constants() {
+    assert_eq!(i8::from(Z8::MIN), i8::MIN);
+    assert_eq!(i8::from(Z8::MAX), i8::MAX);
+    assert_eq!(i16::from(Z16::MIN), i16::MIN);
+    assert_eq!(i16::from(Z16::MAX), i16::MAX);
+    assert_eq!(i32::from(Z32::MIN), i32::MIN);
+    assert_eq!(i32::from(Z32::MAX), i32::MAX);
+    assert_eq!(i64::from(Z64::MIN), i64::MIN);
+    assert_eq!(i64::from(Z64::MAX), i64::MAX);
+}
+
+#[test]
+fn test_signed_sign_extending_conversions() {
+    assert_eq!(Z16::from(Z8::from(100)), Z16::from(100));
+    assert_eq!(Z16::from(Z8::from(-1)), Z16::from(-1));
+    assert_eq!(Z16::from(Z8::MIN), Z16::from(-128));
+    assert_eq!(Z32::from(Z8::from(-1)), Z32::from(-1));
+    assert_eq!(Z32::from(Z16::MIN), Z32::from(-32768));
+    assert_eq!(Z64::from(Z8::MIN), Z64::from(-128));
+    assert_eq!(Z64::from(Z16::from(1234)), Z64::from(1234));
+    assert_eq!(Z64::from(Z32::from(-100000)), Z64::from(-100000));
+}
+
+#[test]
+fn test_byte_n8_round_trip() {
+    let byte = Byte::new([Bit::One; 8]);
+    let n = N8::from(byte);
+    assert_eq!(n, N8::from(255));
+    assert_eq!(Byte::from(n), byte);
+
+    let n = N8::from(0b0110_1001);
+    let byte: Byte = n.into();
+    assert_eq!(format!("{}", byte), "01101001");
+    assert_eq!(N8::from(byte), n);
+}
+
+#[test]
+fn test_nibble_from_u8_and_back() {
+    for i in 0..16u8 {
+        assert_eq!(u8::from(Nibble::from(i)), i);
+    }
+    assert_eq!(u8::from(Nibble::from(0x1F)), 0xF);
+    assert_eq!(Nibble::from(0xF0), Nibble::ZERO);
+    assert_eq!(u8::from(Nibble::ONE), 1);
+}
+
+#[test]
+fn test_nibble_add_sub() {
+    assert_eq!(Nibble::MAX + Nibble::ONE, Nibble::ZERO);
+    assert_eq!(Nibble::from(7) + Nibble::from(8), Nibble::MAX);
+    assert_eq!(Nibble::ZERO - Nibble::ONE, Nibble::MAX);
+    assert_eq!(Nibble::from(9) - Nibble::from(4), Nibble::from(5));
+}
+
+#[test]
+fn test_n16_from_bytes_and_to_bytes() {
+    let n = N16::from_bytes(N8::from(0x12), N8::from(0x34));
+    assert_eq!(n, N16::from(0x1234));
+    assert_eq!(n.to_bytes(), (N8::from(0x12), N8::from(0x34)));
+
+    for (hi, lo) in [(0u8, 0u8), (0xFF, 0x00), (0x00, 0xFF), (0xAB, 0xCD)] {
+        let (hi, lo) = (N8::from(hi), N8::from(lo));
+        assert_eq!(N16::from_bytes(hi, lo).to_bytes(), (hi, lo));
+    }
+}
+
+#[test]
+fn test_unsigned_bit_scan() {
+    for value in [0u32, 1, 0x80, 0x0001_0000, 0x8000_0000, 0x00F0_0F00] {
+        let n = N32::from(value);
+        assert_eq!(n.leading_zeros(), value.leading_zeros());
+        assert_eq!(n.trailing_zeros(), value.trailing_zeros());
+    }
+    assert_eq!(N8::from(0).leading_zeros(), 8);
+    assert_eq!(N8::from(0b0001_0100).leading_zeros(), 3);
+    assert_eq!(N8::from(0b0001_0100).trailing_zeros(), 2);
+    assert_eq!(N16::from(0).trailing_zeros(), 16);
+    assert_eq!(N64::from(1 << 40).trailing_zeros(), 40);
+}
+
+#[test]
+fn test_signed_bit_scan() {
+    assert_eq!(Z8::from(-1).leading_zeros(), 0);
+    assert_eq!(Z16::from(1).leading_zeros(), 15);
+    assert_eq!(Z32::from(0).trailing_zeros(), 32);
+    assert_eq!(Z64::from(i64::MIN).trailing_zeros(), 63);
+}
+
+#[test]
+fn test_unsigned_is_power_of_two() {
+    assert_eq!(N8::from(0).is_power_of_two(), Bool::False);
+    assert_eq!(N8::from(1).is_power_of_two(), Bool::True);
+    assert_eq!(N8::from(3).is_power_of_two(), Bool::False);
+    assert_eq!(N8::from(4).is_power_of_two(), Bool::True);
+    assert_eq!(N8::from(254).is_power_of_two(), Bool::False);
+    assert_eq!(N16::from(0x8000).is_power_of_two(), Bool::True);
+    assert_eq!(N32::from(u32::MAX - 1).is_power_of_two(), Bool::False);
+    assert_eq!(N64::from(1 << 63).is_power_of_two(), Bool::True);
+}
+
+#[test]
+fn test_unsigned_next_power_of_two() {
+    assert_eq!(N8::from(0).next_power_of_two(), N8::from(1));
+    assert_eq!(N8::from(1).next_power_of_two(), N8::from(1));
+    assert_eq!(N8::from(3).next_power_of_two(), N8::from(4));
+    assert_eq!(N8::from(4).next_power_of_two(), N8::from(4));
+    assert_eq!(N8::from(127).next_power_of_two(), N8::from(128));
+    assert_eq!(N8::from(254).next_power_of_two(), N8::from(0));
+    assert_eq!(N16::from(u16::MAX - 1).next_power_of_two(), N16::from(0));
+    assert_eq!(N32::from(1000).next_power_of_two(), N32::from(1024));
+    assert_eq!(N64::from(u64::MAX - 1).next_power_of_two(), N64::from(0));
+}
+
+#[test]
+fn test_unsigned_pow() {
+    assert_eq!(N32::from(2).pow(10), N32::from(1024));
+    assert_eq!(N32::from(7).pow(0), N32::from(1));
+    assert_eq!(N8::from(3).pow(5), N8::from(243));
+    assert_eq!(N8::from(2).pow(8), N8::from(0));
+    assert_eq!(N8::from(3).pow(6), N8::from(3u8.wrapping_pow(6)));
+    assert_eq!(N16::from(10).pow(4), N16::from(10000));
+    assert_eq!(N16::from(10).pow(5), N16::from(10u16.wrapping_pow(5)));
+    assert_eq!(N64::from(3).pow(40), N64::from(3u64.pow(40)));
+}
+
+#[test]
+fn test_signed_pow() {
+    assert_eq!(Z8::from(-2).pow(7), Z8::from(-128));
+    assert_eq!(Z8::from(-3).pow(3), Z8::from(-27));
+    assert_eq!(Z16::from(-10).pow(4), Z16::from(10000));
+    assert_eq!(Z32::from(5).pow(13), Z32::from(5i32.wrapping_pow(13)));
+    assert_eq!(Z64::from(-7).pow(21), Z64::from((-7i64).pow(21)));
+}
+
+#[test]
+fn test_signed_abs() {
+    assert_eq!(Z8::from(-5).abs(), Z8::from(5));
+    assert_eq!(Z8::from(0).abs(), Z8::from(0));
+    assert_eq!(Z8::from(7).abs(), Z8::from(7));
+    assert_eq!(Z8::MIN.abs(), Z8::MIN);
+    assert_eq!(Z16::MIN.abs(), Z16::MIN);
+    assert_eq!(Z32::from(-100000).abs(), Z32::from(100000));
+    assert_eq!(Z64::MIN.abs(), Z64::MIN);
+}
+
+#[test]
+fn test_signed_signum() {
+    assert_eq!(Z8::from(-5).signum(), Z8::from(-1));
+    assert_eq!(Z8::from(0).signum(), Z8::from(0));
+    assert_eq!(Z8::from(7).signum(), Z8::from(1));
+    assert_eq!(Z8::MIN.signum(), Z8::from(-1));
+    assert_eq!(Z16::from(300).signum(), Z16::from(1));
+    assert_eq!(Z32::from(-100000).signum(), Z32::from(-1));
+    assert_eq!(Z64::from(0).signum(), Z64::from(0));
+}
+
+#[test]
+fn test_signed_ordering() {
+    assert!(Z8::from(-5) < Z8::from(3));
+    assert!(Z8::MIN < Z8::MAX);
+    assert!(Z16::from(-1) < Z16::from(0));
+    assert!(Z32::from(100) > Z32::from(-100000));
+    assert_eq!(Z64::from(-3).max(Z64::from(-7)), Z64::from(-3));
+
+    let mut values = vec![
+        Z8::from(3),
+        Z8::from(-128),
+        Z8::from(0),
+        Z8::from(-1),
+        Z8::from(127),
+    ];
+    values.sort();
+    assert_eq!(
+        values,
+        vec![
+            Z8::from(-128),
+            Z8::from(-1),
+            Z8::from(0),
+            Z8::from(3),
+            Z8::from(127)
+        ]
+    );
+}
+
+#[test]
+fn test_wide_unsigned_ordering() {
+    let mut values = [N32::from(300), N32::from(5), N32::from(100)];
+    values.sort();
+    assert_eq!(values, [N32::from(5), N32::from(100), N32::from(300)]);
+
+    assert!(N64::from(1 << 40) > N64::from(1 << 39));
+    let max = [N64::from(3), N64::from(u64::MAX), N64::from(0)]
+        .into_iter()
+        .max();
+    assert_eq!(max, Some(N64::from(u64::MAX)));
+
+    let mut map = std::collections::BTreeMap::new();
+    map.insert(N32::from(2), "two");
+    map.insert(N32::from(1), "one");
+    assert_eq!(map.keys().next(), Some(&N32::from(1)));
+}
+
+#[test]
+fn test_hash_set_membership() {
+    use std::collections::{HashMap, HashSet};
+
+    let set: HashSet<N8> = [1u8, 2, 3, 200].into_iter().map(N8::from).collect();
+    assert!(set.contains(&N8::from(200)));
+    assert!(!set.contains(&N8::from(4)));
+    assert_eq!(set.len(), 4);
+
+    let mut map = HashMap::new();
+    map.insert(Z32::from(-1), "minus one");
+    map.insert(Z32::from(1), "one");
+    assert_eq!(map.get(&Z32::from(-1)), Some(&"minus one"));
+
+    let bits: HashSet<Bit> = [Bit::One, Bit::Zero, Bit::One].into_iter().collect();
+    assert_eq!(bits.len(), 2);
+}
+
+#[test]
+fn test_default_is_zero() {
+    assert_eq!(Bit::default(), Bit::Zero);
+    assert_eq!(Bool::default(), Bool::False);
+    assert_eq!(Byte::default(), Byte::new([Bit::Zero; 8]));
+    assert_eq!(Nibble::default(), Nibble::ZERO);
+    assert_eq!(u16::from(Word::default()), 0);
+    assert_eq!(N8::default(), N8::ZERO);
+    assert_eq!(N32::default(), N32::from(0));
+    assert_eq!(Z64::default(), Z64::from(0));
+    assert_eq!(f32::from(R32::default()), 0.0);
+    assert_eq!(f64::from(R64::default()), 0.0);
+}
+
+#[test]
+fn test_signed_arithmetic_shift_right() {
+    assert_eq!(Z8::from(-8) >> 1, Z8::from(-4));
+    assert_eq!(Z8::from(-1) >> 7, Z8::from(-1));
+    assert_eq!(Z16::from(-1000) >> 3, Z16::from(-125));
+    assert_eq!(Z32::from(-64) >> 2, Z32::from(-16));
+    assert_eq!(Z64::from(-2) >> 1, Z64::from(-1));
+    assert_eq!(Z8::from(3) << 2, Z8::from(12));
+
+    let mut z = Z16::from(-4);
+    z <<= 2;
+    assert_eq!(z, Z16::from(-16));
+    z >>= 4;
+    assert_eq!(z, Z16::from(-1));
+}
+
+#[test]
+fn test_signed_bitwise_ops() {
+    let a = Z8::from(-8);
+    let b = Z8::from(0b0000_1100);
+    assert_eq!(a & b, Z8::from(-8i8 & 12));
+    assert_eq!(a | b, Z8::from(-8i8 | 12));
+    assert_eq!(a ^ b, Z8::from(-8i8 ^ 12));
+    assert_eq!(Z32::from(-1) & Z32::from(0xFF), Z32::from(0xFF));
+    assert_eq!(Z64::from(-2) ^ Z64::from(-1), Z64::from(1));
+}
+
+#[test]
+fn test_signed_assign_ops() {
+    let mut z = Z32::from(10);
+    z += Z32::from(5);
+    z -= Z32::from(20);
+    z *= Z32::from(3);
+    assert_eq!(z, Z32::from(-15));
+}
+
+#[test]
+fn test_wide_unsigned_bitwise_ops() {
+    assert_eq!(N32::from(0xFF00) & N32::from(0x0FF0), N32::from(0x0F00));
+    assert_eq!(N32::from(0xFF00) | N32::from(0x0FF0), N32::from(0xFFF0));
+    assert_eq!(N32::from(0xFF00) ^ N32::from(0x0FF0), N32::from(0xF0F0));
+    assert_eq!(!N16::from(0x00FF), N16::from(0xFF00));
+    assert_eq!(!N64::from(0), N64::from(u64::MAX));
+
+    let mut a = N64::from(0b1100);
+    a &= N64::from(0b1010);
+    assert_eq!(a, N64::from(0b1000));
+    a |= N64::from(0b0001);
+    assert_eq!(a, N64::from(0b1001));
+    a ^= N64::from(0b1111);
+    assert_eq!(a, N64::from(0b0110));
+}
+
+#[test]
+fn test_n8_compound_assign_ops() {
+    let mut n = N8::from(12);
+    n *= N8::from(5);
+    assert_eq!(n, N8::from(60));
+    n /= N8::from(7);
+    assert_eq!(n, N8::from(8));
+    n %= N8::from(3);
+    assert_eq!(n, N8::from(2));
+}
+
+#[test]
+fn test_n16_compound_assign_ops() {
+    let mut n = N16::from(300);
+    n *= N16::from(300);
+    assert_eq!(n, N16::from(300u16.wrapping_mul(300)));
+    n /= N16::from(10);
+    assert_eq!(n, N16::from(300u16.wrapping_mul(300) / 10));
+    n %= N16::from(7);
+    assert_eq!(n, N16::from(300u16.wrapping_mul(300) / 10 % 7));
+}
+
+#[test]
+fn test_n64_compound_assign_ops() {
+    let mut n = N64::from(1_000_000);
+    n *= N64::from(1_000_000);
+    assert_eq!(n, N64::from(1_000_000_000_000));
+    n /= N64::from(3);
+    assert_eq!(n, N64::from(333_333_333_333));
+    n %= N64::from(1000);
+    assert_eq!(n, N64::from(333));
+    assert_eq!(N64::from(20) % N64::from(6), N64::from(2));
+}
+
+#[test]
+fn test_float_partial_ord() {
+    assert!(R32::from(1.5) < R32::from(2.0));
+    assert!(R64::from(-1.0) < R64::from(0.0));
+    assert_eq!(R32::from(0.0), R32::from(-0.0));
+    assert_ne!(R32::from(f32::NAN), R32::from(f32::NAN));
+    assert_eq!(R32::from(f32::NAN).partial_cmp(&R32::from(1.0)), None);
+
+    let mut values = vec![R64::from(3.5), R64::from(-2.0), R64::from(0.25)];
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(
+        values,
+        vec![R64::from(-2.0), R64::from(0.25), R64::from(3.5)]
+    );
+}
+
+#[test]
+fn test_float_total_cmp() {
+    use std::cmp::Ordering;
+
+    let mut values = [
+        R32::from(f32::NAN),
+        R32::from(1.0),
+        R32::from(-0.0),
+        R32::from(0.0),
+        R32::from(f32::NEG_INFINITY),
+        R32::from(-f32::NAN),
+    ];
+    values.sort_by(R32::total_cmp);
+    let sorted: Vec<u32> = values.iter().map(|&r| f32::from(r).to_bits()).collect();
+    let expected = [
+        (-f32::NAN).to_bits(),
+        f32::NEG_INFINITY.to_bits(),
+        (-0.0f32).to_bits(),
+        0.0f32.to_bits(),
+        1.0f32.to_bits(),
+        f32::NAN.to_bits(),
+    ];
+    assert_eq!(sorted, expected);
+
+    assert_eq!(R64::from(-0.0).total_cmp(&R64::from(0.0)), Ordering::Less);
+    assert_eq!(
+        R64::from(f64::NAN).total_cmp(&R64::from(f64::INFINITY)),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_float_classification() {
+    assert_eq!(R32::from(f32::NAN).is_nan(), Bool::True);
+    assert_eq!(R32::from(1.0).is_nan(), Bool::False);
+    assert_eq!(R32::from(f32::INFINITY).is_infinite(), Bool::True);
+    assert_eq!(R32::from(f32::INFINITY).is_finite(), Bool::False);
+    assert_eq!(R64::from(-0.0).is_sign_negative(), Bool::True);
+    assert_eq!(R64::from(2.0).is_finite(), Bool::True);
+    assert_eq!(R64::from(f64::NAN).is_infinite(), Bool::False);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_float_math_methods() {
+    assert_eq!(R32::from(-2.5).abs(), R32::from(2.5));
+    assert_eq!(R32::from(-2.5).floor(), R32::from(-3.0));
+    assert_eq!(R32::from(-2.5).ceil(), R32::from(-2.0));
+    assert_eq!(R32::from(-2.5).round(), R32::from(-3.0));
+    assert_eq!(R32::from(-2.5).trunc(), R32::from(-2.0));
+    assert_eq!(R32::from(16.0).sqrt(), R32::from(4.0));
+    assert_eq!(R64::from(2.5).round(), R64::from(3.0));
+    assert_eq!(R64::from(81.0).sqrt(), R64::from(9.0));
+    assert_eq!(R64::from(-1.0).sqrt().is_nan(), Bool::True);
+}
+
+#[test]
+fn test_float_neg() {
+    assert_eq!(-R32::from(3.0), R32::from(-3.0));
+    assert_eq!(-R32::from(-3.0), R32::from(3.0));
+    assert!(f32::from(-R32::from(0.0)).is_sign_negative());
+    assert_eq!(
+        f32::from(-R32::from(f32::NAN)).to_bits(),
+        (-f32::NAN).to_bits()
+    );
+    assert_eq!(-R64::from(1.25), R64::from(-1.25));
+    assert!(f64::from(-R64::from(0.0)).is_sign_negative());
+    assert!(f64::from(-R64::from(-0.0)).is_sign_positive());
+}
+
+#[test]
+fn test_r32_decomposition() {
+    let one = R32::from(1.0);
+    assert_eq!(one.sign_bit(), Bit::Zero);
+    assert_eq!(one.exponent_bits(), N8::from(127));
+    assert_eq!(one.mantissa_bits(), N32::from(0));
+
+    let minus_two = R32::from(-2.0);
+    assert_eq!(minus_two.sign_bit(), Bit::One);
+    assert_eq!(minus_two.exponent_bits(), N8::from(128));
+    assert_eq!(minus_two.mantissa_bits(), N32::from(0));
+
+    let subnormal = R32::from(f32::from_bits(0x0000_0001));
+    assert_eq!(subnormal.sign_bit(), Bit::Zero);
+    assert_eq!(subnormal.exponent_bits(), N8::from(0));
+    assert_eq!(subnormal.mantissa_bits(), N32::from(1));
+
+    let half_and_a_bit = R32::from(1.5);
+    assert_eq!(half_and_a_bit.mantissa_bits(), N32::from(1 << 22));
+}
+
+#[test]
+fn test_r64_decomposition() {
+    let one = R64::from(1.0);
+    assert_eq!(one.sign_bit(), Bit::Zero);
+    assert_eq!(one.exponent_bits(), N16::from(1023));
+    assert_eq!(one.mantissa_bits(), N64::from(0));
+
+    let minus_two = R64::from(-2.0);
+    assert_eq!(minus_two.sign_bit(), Bit::One);
+    assert_eq!(minus_two.exponent_bits(), N16::from(1024));
+
+    let subnormal = R64::from(f64::from_bits(0x0000_0000_0000_0003));
+    assert_eq!(subnormal.exponent_bits(), N16::from(0));
+    assert_eq!(subnormal.mantissa_bits(), N64::from(3));
+}
+
+#[test]
+fn test_r16_known_values() {
+    assert_eq!(R16::from(0.0).to_bits(), 0x0000);
+    assert_eq!(R16::from(-0.0).to_bits(), 0x8000);
+    assert_eq!(R16::from(1.0).to_bits(), 0x3C00);
+    assert_eq!(R16::from(-2.0).to_bits(), 0xC000);
+    assert_eq!(R16::from(65504.0).to_bits(), 0x7BFF);
+    assert_eq!(R16::MAX, R16::from(65504.0));
+    assert_eq!(f32::from(R16::MAX), 65504.0);
+    assert_eq!(R16::from(f32::INFINITY).to_bits(), 0x7C00);
+    assert!(f32::from(R16::from(f32::NAN)).is_nan());
+}
+
+#[test]
+fn test_r16_rounding() {
+    // Halfway between 65504 and 65536 rounds to even, i.e. infinity.
+    assert_eq!(R16::from(65520.0).to_bits(), 0x7C00);
+    assert_eq!(R16::from(65519.0).to_bits(), 0x7BFF);
+    assert_eq!(R16::from(1.0e6).to_bits(), 0x7C00);
+    // Ties between 1.0 and the next binary16 value round to even.
+    assert_eq!(R16::from(1.0 + 2f32.powi(-11)).to_bits(), 0x3C00);
+    assert_eq!(R16::from(1.0 + 3.0 * 2f32.powi(-11)).to_bits(), 0x3C02);
+    // Smallest subnormal and underflow to zero.
+    assert_eq!(R16::from(2f32.powi(-24)).to_bits(), 0x0001);
+    assert_eq!(R16::from(2f32.powi(-25)).to_bits(), 0x0000);
+    assert_eq!(R16::from(1.5 * 2f32.powi(-25)).to_bits(), 0x0001);
+    assert_eq!(f32::from(R16::from_bits(0x0001)), 2f32.powi(-24));
+    assert_eq!(f32::from(R16::from_bits(0x03FF)), 1023.0 * 2f32.powi(-24));
+}
+
+#[test]
+fn test_r16_round_trip_all_finite() {
+    for bits in 0..=u16::MAX {
+        let value = R16::from_bits(bits);
+        let wide = f32::from(value);
+        if wide.is_nan() {
+            continue;
+        }
+        assert_eq!(R16::from(wide).to_bits(), bits);
+    }
+}
+
+#[test]
+fn test_r16_arithmetic() {
+    assert_eq!(R16::from(1.5) + R16::from(2.25), R16::from(3.75));
+    assert_eq!(R16::from(1.0) - R16::from(3.0), R16::from(-2.0));
+    assert_eq!(R16::from(3.0) * R16::from(0.5), R16::from(1.5));
+    assert_eq!(R16::from(1.0) / R16::from(4.0), R16::from(0.25));
+    assert_eq!(R16::from(7.0) % R16::from(2.0), R16::from(1.0));
+    assert_eq!((R16::MAX + R16::MAX).to_bits(), 0x7C00);
+    assert_eq!(format!("{}", R16::from(0.5)), "0.5");
+}
+
+#[test]
+fn test_n128_from_u128_and_back() {
+    let n = N128::from(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+    assert_eq!(u128::from(n), 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+}
+
+#[test]
+fn test_n128_arithmetic() {
+    let a = N128::from(u64::MAX as u128 * 3);
+    let b = N128::from(u64::MAX as u128);
+    assert_eq!(u128::from(a + b), u64::MAX as u128 * 4);
+    assert_eq!(u128::from(a - b), u64::MAX as u128 * 2);
+    assert_eq!(u128::from(b * N128::from(5)), u64::MAX as u128 * 5);
+    assert_eq!(u128::from(a / b), 3);
+    assert_eq!(u128::from(a % N128::from(7)), (u64::MAX as u128 * 3) % 7);
+    assert_eq!(u128::from(!N128::from(0)), u128::MAX);
+}
+
+#[test]
+fn test_n128_conversions() {
+    let wide = N128::from(N64::from(u64::MAX));
+    assert_eq!(u128::from(wide), u64::MAX as u128);
+    assert_eq!(u64::from(N64::try_from(wide).unwrap()), u64::MAX);
+    assert!(N64::try_from(N128::from(1u128 << 64)).is_err());
+    assert_eq!(format!("{}", N128::from(u128::MAX)), u128::MAX.to_string());
+}
+
+#[test]
+fn test_z128_from_i128_and_back() {
+    let n = Z128::from(-0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+    assert_eq!(i128::from(n), -0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+    assert_eq!(i128::from(Z128::MIN), i128::MIN);
+    assert_eq!(i128::from(Z128::MAX), i128::MAX);
+}
+
+#[test]
+fn test_z128_arithmetic() {
+    let a = Z128::from(i64::MIN as i128 * 3);
+    let b = Z128::from(i64::MAX as i128);
+    assert_eq!(i128::from(a + b), i64::MIN as i128 * 3 + i64::MAX as i128);
+    assert_eq!(i128::from(a - b), i64::MIN as i128 * 3 - i64::MAX as i128);
+    assert_eq!(i128::from(a * Z128::from(-2)), i64::MIN as i128 * -6);
+    assert_eq!(i128::from(a / Z128::from(3)), i64::MIN as i128);
+    assert_eq!(i128::from(a % Z128::from(7)), (i64::MIN as i128 * 3) % 7);
+    assert_eq!(i128::from(-a), i64::MIN as i128 * -3);
+    assert_eq!(i128::from(!Z128::from(0)), -1);
+}
+
+#[test]
+fn test_z128_sign_extension() {
+    assert_eq!(
+        i128::from(Z128::from(Z64::from(i64::MIN))),
+        i64::MIN as i128
+    );
+    assert_eq!(i128::from(Z128::from(Z8::from(-5))), -5);
+    assert_eq!(format!("{}", Z128::from(i128::MIN)), i128::MIN.to_string());
+}
+
+#[test]
+fn test_nuint_12_wrapping() {
+    let max = Nuint::<12>::MAX;
+    assert_eq!(u128::try_from(max).unwrap(), 4095);
+    assert_eq!(max + Nuint::from(1), Nuint::ZERO);
+    assert_eq!(Nuint::<12>::ZERO - Nuint::from(1), max);
+    assert_eq!(
+        u128::try_from(Nuint::<12>::from(100) * Nuint::from(50)).unwrap(),
+        5000 % 4096
+    );
+    assert_eq!(
+        u128::try_from(Nuint::<12>::from(5000)).unwrap(),
+        5000 % 4096
+    );
+    assert_eq!(max.overflowing_add(Nuint::from(1)).1, Bool::True);
+}
+
+#[test]
+fn test_nuint_div_rem() {
+    for a in [0u128, 1, 7, 100, 2047, 4095] {
+        for b in [1u128, 3, 10, 2048, 4095] {
+            let x = Nuint::<12>::from(a);
+            let y = Nuint::<12>::from(b);
+            assert_eq!(u128::try_from(x / y).unwrap(), a / b);
+            assert_eq!(u128::try_from(x % y).unwrap(), a % b);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_nuint_div_by_zero() {
+    let _ = Nuint::<12>::from(1) / Nuint::ZERO;
+}
+
+#[test]
+fn test_nuint_rgb_packing() {
+    let red = Nuint::<24>::from(0x12);
+    let green = Nuint::<24>::from(0x34);
+    let blue = Nuint::<24>::from(0x56);
+    let packed = (red << 16) | (green << 8) | blue;
+    assert_eq!(u128::try_from(packed).unwrap(), 0x123456);
+    assert_eq!(packed >> 16, red);
+    assert_eq!(packed & Nuint::from(0xFF), blue);
+    assert_eq!(packed << 24, Nuint::ZERO);
+}
+
+#[test]
+fn test_nuint_matches_fixed_width_types() {
+    for a in 0..=255u8 {
+        let n = Nuint::<8>::from(N8::from(a));
+        assert_eq!(u8::from(N8::from(n)), a);
+        assert_eq!(N8::from(n + Nuint::from(77)), N8::from(a) + N8::from(77));
+    }
+    let n = Nuint::<64>::from(N64::from(u64::MAX));
+    assert_eq!(u64::from(N64::from(n)), u64::MAX);
+}
+
+#[test]
+fn test_byte_index() {
+    let mut byte = Byte::new([
+        Bit::One,
+        Bit::Zero,
+        Bit::Zero,
+        Bit::Zero,
+        Bit::Zero,
+        Bit::Zero,
+        Bit::Zero,
+        Bit::Zero,
+    ]);
+    assert_eq!(byte[0], Bit::One);
+    assert_eq!(byte[7], Bit::Zero);
+    byte[7] = Bit::One;
+    assert_eq!(byte[7], Bit::One);
+}
+
+#[test]
+fn test_nibble_and_word_index_mut() {
+    let mut nibble = Nibble::from(0b0001);
+    nibble[3] = Bit::One;
+    assert_eq!(u8::from(nibble), 0b1001);
+    let mut word = Word::from(0u16);
+    word[15] = Bit::One;
+    word[0] = Bit::One;
+    assert_eq!(u16::from(word), 0x8001);
+}
+
+#[test]
+fn test_integer_index() {
+    let n = N8::from(0b1000_0001);
+    assert_eq!((n[0], n[7], n[1]), (Bit::One, Bit::One, Bit::Zero));
+    assert_eq!(N64::from(1 << 63)[63], Bit::One);
+    assert_eq!(N128::from(1)[0], Bit::One);
+    let z = Z32::from(-1);
+    assert_eq!((z[0], z[31]), (Bit::One, Bit::One));
+    assert_eq!(Z16::from(1)[15], Bit::Zero);
+    assert_eq!(Nuint::<12>::MAX[11], Bit::One);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_index_out_of_range() {
+    let n = N16::from(0);
+    let _ = n[16];
+}
+
+#[test]
+fn test_n8_bits_round_trip() {
+    let n = N8::from(0b1010);
+    let bits: Vec<Bit> = n.into_iter().collect();
+    assert_eq!(&bits[..4], &[Bit::Zero, Bit::One, Bit::Zero, Bit::One]);
+    assert_eq!(N8::from_iter(bits), n);
+    assert_eq!(n.iter_bits().filter(|&b| b == Bit::One).count(), 2);
+}
+
+#[test]
+fn test_iter_bits_is_lsb_first() {
+    let byte: Byte = [Bit::One, Bit::One].into_iter().collect();
+    assert_eq!(Byte::from_iter(byte), byte);
+    assert_eq!(byte.iter_bits().next(), Some(Bit::One));
+    assert_eq!(Nibble::from(0b1000).into_iter().last(), Some(Bit::One));
+    assert_eq!(
+        N16::from(1).iter_bits().position(|b| b == Bit::One),
+        Some(0)
+    );
+    assert_eq!(
+        N32::from(1 << 31).into_iter().position(|b| b == Bit::One),
+        Some(31)
+    );
+    assert_eq!(N64::from(u64::MAX).iter_bits().count(), 64);
+    assert_eq!(N128::from(0).into_iter().count(), 128);
+}
+
+#[test]
+fn test_bit_mux_truth_table() {
+    for sel in [Bit::Zero, Bit::One] {
+        for a in [Bit::Zero, Bit::One] {
+            for b in [Bit::Zero, Bit::One] {
+                let expected = if sel == Bit::One { a } else { b };
+                assert_eq!(Bit::mux(sel, a, b), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_byte_mux() {
+    let a = Byte::from(N8::from(0xA5));
+    let b = Byte::from(N8::from(0x3C));
+    assert_eq!(Byte::mux(Bit::One, a, b), a);
+    assert_eq!(Byte::mux(Bit::Zero, a, b), b);
+}
+
+#[test]
+fn test_half_adder_truth_table() {
+    assert_eq!(
+        Bit::half_adder(Bit::Zero, Bit::Zero),
+        (Bit::Zero, Bit::Zero)
+    );
+    assert_eq!(Bit::half_adder(Bit::Zero, Bit::One), (Bit::One, Bit::Zero));
+    assert_eq!(Bit::half_adder(Bit::One, Bit::Zero), (Bit::One, Bit::Zero));
+    assert_eq!(Bit::half_adder(Bit::One, Bit::One), (Bit::Zero, Bit::One));
+}
+
+#[test]
+fn test_full_adder_and_subtractor_truth_tables() {
+    let bits = [Bit::Zero, Bit::One];
+    for a in bits {
+        for b in bits {
+            for c in bits {
+                let (a_n, b_n, c_n) = (u8::from(a), u8::from(b), u8::from(c));
+                let (sum, carry) = Bit::full_add(a, b, c);
+                assert_eq!(u8::from(sum) + 2 * u8::from(carry), a_n + b_n + c_n);
+                let (diff, borrow) = Bit::full_sub(a, b, c);
+                assert_eq!(
+                    u8::from(diff) as i8 - 2 * u8::from(borrow) as i8,
+                    a_n as i8 - b_n as i8 - c_n as i8
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_alu_overflowing_add_flags() {
+    let result = Alu::execute(AluOp::Add, N8::from(200), N8::from(100));
+    assert_eq!(u8::from(result.value), 44);
+    assert_eq!(result.flags.carry, Bool::True);
+    assert_eq!(result.flags.overflow, Bool::False);
+    assert_eq!(result.flags.zero, Bool::False);
+
+    let result = Alu::execute(AluOp::Add, N8::from(0x7F), N8::from(1));
+    assert_eq!(u8::from(result.value), 0x80);
+    assert_eq!(result.flags.carry, Bool::False);
+    assert_eq!(result.flags.overflow, Bool::True);
+    assert_eq!(result.flags.negative, Bool::True);
+}
+
+#[test]
+fn test_alu_zero_producing_sub_flags() {
+    let result = Alu::execute(AluOp::Sub, N8::from(42), N8::from(42));
+    assert_eq!(result.value, N8::ZERO);
+    assert_eq!(
+        result.flags,
+        Flags {
+            zero: Bool::True,
+            carry: Bool::False,
+            negative: Bool::False,
+            overflow: Bool::False,
+        }
+    );
+
+    let result = Alu::execute(AluOp::Sub, N8::from(0x80), N8::from(1));
+    assert_eq!(u8::from(result.value), 0x7F);
+    assert_eq!(result.flags.overflow, Bool::True);
+    let result = Alu::execute(AluOp::Sub, N8::from(1), N8::from(2));
+    assert_eq!(result.flags.carry, Bool::True);
+}
+
+#[test]
+fn test_alu_logic_ops() {
+    let a = N8::from(0b1100_1010);
+    let b = N8::from(0b1010_0110);
+    assert_eq!(u8::from(Alu::execute(AluOp::And, a, b).value), 0b1000_0010);
+    assert_eq!(u8::from(Alu::execute(AluOp::Or, a, b).value), 0b1110_1110);
+    assert_eq!(u8::from(Alu::execute(AluOp::Xor, a, b).value), 0b0110_1100);
+    let not = Alu::execute(AluOp::Not, a, b);
+    assert_eq!(u8::from(not.value), 0b0011_0101);
+    assert_eq!(not.flags.carry, Bool::False);
+}
+
+#[test]
+fn test_n8_shift_carry() {
+    let (value, carry) = N8::from(0b1000_0000).shift_left_carry(1);
+    assert_eq!((u8::from(value), carry), (0, Bit::One));
+    let (value, carry) = N8::from(0b0000_0011).shift_right_carry(1);
+    assert_eq!((u8::from(value), carry), (0b1, Bit::One));
+    assert_eq!(
+        N8::from(0xFF).shift_left_carry(0),
+        (N8::from(0xFF), Bit::Zero)
+    );
+    assert_eq!(
+        N8::from(0x81).shift_left_carry(8),
+        (N8::from(0x81), Bit::Zero)
+    );
+    assert_eq!(
+        N8::from(0x81).shift_right_carry(9),
+        (N8::from(0x40), Bit::One)
+    );
+}
+
+#[test]
+fn test_shift_carry_matches_primitive() {
+    let x = 0xDEAD_BEEF_1234_5678u64;
+    for n in 1..64 {
+        let (value, carry) = N64::from(x).shift_left_carry(n);
+        assert_eq!(u64::from(value), x << n);
+        assert_eq!(u8::from(carry) as u64, (x >> (64 - n)) & 1);
+        let (value, carry) = N64::from(x).shift_right_carry(n);
+        assert_eq!(u64::from(value), x >> n);
+        assert_eq!(u8::from(carry) as u64, (x >> (n - 1)) & 1);
+    }
+    for n in 64..200 {
+        assert_eq!(
+            u64::from(N64::from(x).shift_left_carry(n).0),
+            x.wrapping_shl(n)
+        );
+        assert_eq!(
+            u64::from(N64::from(x).shift_right_carry(n).0),
+            x.wrapping_shr(n)
+        );
+    }
+    assert_eq!(N16::from(0x8000).shift_left_carry(1).1, Bit::One);
+    assert_eq!(N32::from(1).shift_right_carry(1), (N32::from(0), Bit::One));
+    assert_eq!(N128::from(1).shift_left_carry(127).0, N128::from(1 << 127));
+}
+
+#[test]
+fn test_widening_mul() {
+    assert_eq!(N8::from(255).widening_mul(N8::from(255)), N16::from(65025));
+    for a in (0..=255u8).step_by(7) {
+        for b in 0..=255u8 {
+            assert_eq!(
+                u16::from(N8::from(a).widening_mul(N8::from(b))),
+                a as u16 * b as u16
+            );
+        }
+    }
+    assert_eq!(
+        u32::from(N16::from(u16::MAX).widening_mul(N16::from(u16::MAX))),
+        u16::MAX as u32 * u16::MAX as u32
+    );
+}
+
+#[test]
+fn test_wrapping_wrapper() {
+    assert_eq!(
+        Wrapping(N8::from(255)) + Wrapping(N8::from(1)),
+        Wrapping(N8::from(0))
+    );
+    assert_eq!(
+        Wrapping(N8::from(0)) - Wrapping(N8::from(1)),
+        Wrapping(N8::MAX)
+    );
+    assert_eq!(
+        Wrapping(N16::from(0x100)) * Wrapping(N16::from(0x100)),
+        Wrapping(N16::from(0))
+    );
+    let mut z = Wrapping(Z8::MAX);
+    z += Wrapping(Z8::from(1));
+    assert_eq!(z, Wrapping(Z8::MIN));
+    assert_eq!(format!("{}", Wrapping(N32::from(7))), "7");
+}
+
+#[test]
+fn test_checked_wrapper() {
+    assert_eq!(Checked(N8::from(255)) + Checked(N8::from(1)), None);
+    assert_eq!(
+        Checked(N8::from(200)) + Checked(N8::from(55)),
+        Some(Checked(N8::from(255)))
+    );
+    assert_eq!(Checked(N64::from(0)) - Checked(N64::from(1)), None);
+    assert_eq!(Checked(Z16::MIN) * Checked(Z16::from(-1)), None);
+    assert_eq!(
+        Checked(Z32::from(-6)) * Checked(Z32::from(7)),
+        Some(Checked(Z32::from(-42)))
+    );
+}
+
+#[test]
+fn test_endian_bytes() {
+    assert_eq!(N16::from(0x1234).to_be_bytes(), [0x12, 0x34]);
+    assert_eq!(N16::from(0x1234).to_le_bytes(), [0x34, 0x12]);
+    assert_eq!(
+        N32::from(0x1234_5678).to_le_bytes(),
+        0x1234_5678u32.to_le_bytes()
+    );
+    assert_eq!(Z64::from(-2).to_be_bytes(), (-2i64).to_be_bytes());
+}
+
+#[test]
+fn test_endian_bytes_round_trip() {
+    let n = N64::from(0x0102_0304_0506_0708);
+    assert_eq!(N64::from_le_bytes(n.to_le_bytes()), n);
+    assert_eq!(N64::from_be_bytes(n.to_be_bytes()), n);
+    let z = Z32::from(-123_456);
+    assert_eq!(Z32::from_le_bytes(z.to_le_bytes()), z);
+    assert_eq!(Z32::from_be_bytes(z.to_be_bytes()), z);
+    assert_eq!(Z16::from_be_bytes([0xFF, 0xFE]), Z16::from(-2));
+    let wide = N128::from(u128::MAX - 1);
+    assert_eq!(N128::from_be_bytes(wide.to_be_bytes()), wide);
+    assert_eq!(Z128::from_le_bytes([0xFF; 16]), Z128::from(-1));
+}
+
+#[test]
+fn test_swap_bytes() {
+    assert_eq!(N32::from(0x12345678).swap_bytes(), N32::from(0x78563412));
+    assert_eq!(N16::from(0xABCD).swap_bytes(), N16::from(0xCDAB));
+    let x = 0x0123_4567_89AB_CDEFu64;
+    assert_eq!(u64::from(N64::from(x).swap_bytes()), x.swap_bytes());
+    assert_eq!(
+        i16::from(Z16::from(0x00FF).swap_bytes()),
+        0x00FFi16.swap_bytes()
+    );
+    assert_eq!(i32::from(Z32::from(-2).swap_bytes()), (-2i32).swap_bytes());
+    assert_eq!(i64::from(Z64::from(-2).swap_bytes()), (-2i64).swap_bytes());
+    let y = 0x0011_2233_4455_6677_8899_AABB_CCDD_EEFFu128;
+    assert_eq!(u128::from(N128::from(y).swap_bytes()), y.swap_bytes());
+    assert_eq!(
+        i128::from(Z128::from(-7).swap_bytes()),
+        (-7i128).swap_bytes()
+    );
+}
+
+#[test]
+fn test_reversed_bits() {
+    let original = N8::from(0b0000_0110);
+    let reversed = original.reversed_bits();
+    assert_eq!(u8::from(original), 0b0000_0110);
+    assert_eq!(u8::from(reversed), 0b0110_0000);
+    let mut mutated = original;
+    mutated.reverse_bits();
+    assert_eq!(reversed, mutated);
+    assert_eq!(u32::from(N32::from(1).reversed_bits()), 1u32.reverse_bits());
+    assert_eq!(u64::from(N64::from(3).reversed_bits()), 3u64.reverse_bits());
+}
+
+#[test]
+fn test_n8_gray_code_adjacent_codes_differ_by_one_bit() {
+    for i in 0..255u8 {
+        let a = u8::from(N8::from(i).to_gray());
+        let b = u8::from(N8::from(i + 1).to_gray());
+        assert_eq!((a ^ b).count_ones(), 1);
+    }
+}
+
+#[test]
+fn test_gray_code_round_trip() {
+    for i in 0..=255u8 {
+        let n = N8::from(i);
+        assert_eq!(u8::from(n.to_gray()), i ^ (i >> 1));
+        assert_eq!(n.to_gray().from_gray(), n);
+    }
+    for x in [0u64, 1, 0xDEAD_BEEF, u64::MAX] {
+        assert_eq!(u64::from(N64::from(x).to_gray()), x ^ (x >> 1));
+        assert_eq!(N64::from(x).to_gray().from_gray(), N64::from(x));
+    }
+    assert_eq!(N16::from(0xBEEF).to_gray().from_gray(), N16::from(0xBEEF));
+    assert_eq!(N32::from(12345).to_gray().from_gray(), N32::from(12345));
+}
+
+#[test]
+fn test_parity() {
+    assert_eq!(N8::from(0b111).parity(), Bit::One);
+    assert_eq!(N8::from(0b1010).parity(), Bit::Zero);
+    assert_eq!(N8::from(0b1010).has_even_parity(), Bool::True);
+    assert_eq!(N8::from(0).parity(), Bit::Zero);
+    assert_eq!(N64::from(u64::MAX).parity(), Bit::Zero);
+    assert_eq!(N32::from(1 << 31).has_even_parity(), Bool::False);
+    assert_eq!(Z8::from(-1).parity(), Bit::Zero);
+    assert_eq!(Z16::from(-2).parity(), Bit::One);
+    assert_eq!(Nuint::<12>::from(0b111).parity(), Bit::One);
+    assert_eq!(Nuint::<12>::MAX.has_even_parity(), Bool::True);
+    assert_eq!(Nuint::<3>::MAX.parity(), Bit::One);
+}
+
+#[test]
+fn test_hamming_distance() {
+    let a = N8::from(0b1111);
+    let b = N8::from(0b1001);
+    assert_eq!(a.hamming_distance(&b), 2);
+    assert_eq!(b.hamming_distance(&a), 2);
+    assert_eq!(a.hamming_distance(&a), 0);
+    assert_eq!(N64::from(0).hamming_distance(&N64::from(u64::MAX)), 64);
+    assert_eq!(Z32::from(0).hamming_distance(&Z32::from(-1)), 32);
+    assert_eq!(N16::from(0x00FF).hamming_distance(&N16::from(0x0F0F)), 8);
+}
+
+#[test]
+fn test_bool_primitive_conversions() {
+    for value in [false, true] {
+        let b: Bool = value.into();
+        assert_eq!(bool::from(b), value);
+        assert_eq!(Bool::from(value).to_bool(), value);
+    }
+    assert_eq!(Bool::from(true), Bool::True);
+    assert!(!bool::from(Bool::False));
+}
+
+#[test]
+fn test_bit_bool_conversions() {
+    assert_eq!(Bool::from(Bit::Zero), Bool::False);
+    assert_eq!(Bool::from(Bit::One), Bool::True);
+    assert_eq!(Bit::from(Bool::False), Bit::Zero);
+    assert_eq!(Bit::from(Bool::True), Bit::One);
+    for bit in [Bit::Zero, Bit::One] {
+        let b: Bool = bit.into();
+        assert_eq!(Bit::from(b), bit);
+    }
+}
+
+#[test]
+fn test_bool_implies_truth_table() {
+    assert_eq!(Bool::False.implies(&Bool::False), Bool::True);
+    assert_eq!(Bool::False.implies(&Bool::True), Bool::True);
+    assert_eq!(Bool::True.implies(&Bool::False), Bool::False);
+    assert_eq!(Bool::True.implies(&Bool::True), Bool::True);
+}
+
+#[test]
+fn test_bool_nand_nor_xnor() {
+    for a in [false, true] {
+        for b in [false, true] {
+            let (x, y) = (Bool::from(a), Bool::from(b));
+            assert_eq!(x.nand(&y), !(a && b));
+            assert_eq!(x.nor(&y), !(a || b));
+            assert_eq!(x.xnor(&y), a == b);
+        }
+    }
+}
+
+#[test]
+fn test_bool_select() {
+    let a = N32::from(0xAAAA_AAAA);
+    let b = N32::from(0x5555_5555);
+    assert_eq!(Bool::True.select(a, b), a);
+    assert_eq!(Bool::False.select(a, b), b);
+}
+
+#[test]
+fn test_either_accessors() {
+    let left: Either<u8, &str> = Either::Left(7);
+    let right: Either<u8, &str> = Either::Right("seven");
+    assert!(left.is_left() && !left.is_right());
+    assert!(right.is_right() && !right.is_left());
+    assert_eq!(left.as_ref().left(), Some(&7));
+    assert_eq!(right.as_ref().left(), None);
+    assert_eq!(right.as_ref().right(), Some(&"seven"));
+    assert_eq!(left.as_ref().right(), None);
+    assert_eq!(Either::<u8, &str>::Left(7).swap().unwrap_right(), 7);
+    assert_eq!(
+        Either::<u8, &str>::Right("seven").swap().unwrap_left(),
+        "seven"
+    );
+    assert_eq!(left.unwrap_left(), 7);
+    assert_eq!(right.unwrap_right(), "seven");
+}
+
+#[test]
+#[should_panic(expected = "on a `Right` value")]
+fn test_either_unwrap_left_on_right() {
+    let right: Either<u8, u8> = Either::Right(1);
+    right.unwrap_left();
+}
+
+#[test]
+#[should_panic(expected = "on a `Left` value")]
+fn test_either_unwrap_right_on_left() {
+    let left: Either<u8, u8> = Either::Left(1);
+    left.unwrap_right();
+}
+
+#[test]
+fn test_either_fold_to_string() {
+    let left: Either<u32, &str> = Either::Left(42);
+    let right: Either<u32, &str> = Either::Right("forty-two");
+    assert_eq!(left.either(|n| n.to_string(), String::from), "42");
+    assert_eq!(right.either(|n| n.to_string(), String::from), "forty-two");
+}
+
+#[test]
+fn test_either_into_inner() {
+    let left: Either<String, String> = Either::Left(String::from("left"));
+    let right: Either<String, String> = Either::Right(String::from("right"));
+    assert_eq!(left.into_inner(), "left");
+    assert_eq!(right.into_inner(), "right");
+}
+
+#[test]
+fn test_either_eq_and_display() {
+    let a: Either<N8, R32> = Either::Left(N8::from(5));
+    let b: Either<N8, R32> = Either::Left(N8::from(5));
+    assert_eq!(a, b);
+    assert_ne!(a, Either::Left(N8::from(6)));
+    let right: Either<N8, Z16> = Either::Right(Z16::from(-3));
+    assert_eq!(format!("{}", right), "Right(-3)");
+    assert_eq!(format!("{}", a), "Left(5)");
+    assert_eq!(a.map_left(|n| n + N8::ONE), Either::Left(N8::from(6)));
+}
+
+#[test]
+fn test_const_constructors_match_from() {
+    const MASK: N32 = N32::from_u32(0xFF00);
+    const WIDE: N64 = N64::from_u64(u64::MAX - 1);
+    const SHORT: N16 = N16::from_u16(0xBEEF);
+    const HUGE: N128 = N128::from_u128(1 << 100);
+    const NEG8: Z8 = Z8::from_i8(i8::MIN);
+    const NEG16: Z16 = Z16::from_i16(-2);
+    const NEG32: Z32 = Z32::from_i32(-123_456);
+    const NEG64: Z64 = Z64::from_i64(i64::MIN + 1);
+    const NEG128: Z128 = Z128::from_i128(-1);
+    assert_eq!(MASK, N32::from(0xFF00));
+    assert_eq!(WIDE, N64::from(u64::MAX - 1));
+    assert_eq!(SHORT, N16::from(0xBEEF));
+    assert_eq!(HUGE, N128::from(1 << 100));
+    assert_eq!(NEG8, Z8::from(i8::MIN));
+    assert_eq!(NEG16, Z16::from(-2));
+    assert_eq!(NEG32, Z32::from(-123_456));
+    assert_eq!(NEG64, Z64::from(i64::MIN + 1));
+    assert_eq!(NEG128, Z128::from(-1));
+}
+
+fn generic_sum<T: KarccInt>(xs: &[T]) -> T {
+    let mut total = T::ZERO;
+    for &x in xs {
+        total += x;
+    }
+    total
+}
+
+fn generic_round_trip<T: KarccInt>(value: T::Primitive) -> T::Primitive {
+    let x: T = value.into();
+    let bits = x.count_ones() + x.count_zeros();
+    assert_eq!(bits as usize, core::mem::size_of::<T::Primitive>() * 8);
+    T::Primitive::from(x)
+}
+
+#[test]
+fn test_karcc_int_generic_code() {
+    assert_eq!(
+        generic_sum(&[N8::from(1), N8::from(2), N8::from(3)]),
+        N8::from(6)
+    );
+    assert_eq!(
+        generic_sum(&[Z32::from(-10), Z32::from(4), Z32::from(1)]),
+        Z32::from(-5)
+    );
+    assert_eq!(generic_sum::<N64>(&[]), N64::from(0));
+    assert_eq!(<N16 as KarccInt>::MAX, N16::from(u16::MAX));
+    assert_eq!(<Z64 as KarccInt>::MIN, Z64::from(i64::MIN));
+    assert_eq!(<N32 as KarccInt>::ONE, N32::from(1));
+    assert_eq!(generic_round_trip::<N8>(0xA5), 0xA5);
+    assert_eq!(generic_round_trip::<N16>(0xBEEF), 0xBEEF);
+    assert_eq!(generic_round_trip::<N32>(u32::MAX), u32::MAX);
+    assert_eq!(generic_round_trip::<N64>(1 << 40), 1 << 40);
+    assert_eq!(generic_round_trip::<N128>(u128::MAX), u128::MAX);
+    assert_eq!(generic_round_trip::<Z8>(-100), -100);
+    assert_eq!(generic_round_trip::<Z16>(i16::MIN), i16::MIN);
+    assert_eq!(generic_round_trip::<Z32>(-1), -1);
+    assert_eq!(generic_round_trip::<Z64>(i64::MAX), i64::MAX);
+    assert_eq!(generic_round_trip::<Z128>(i128::MIN), i128::MIN);
+}
+
+#[test]
+fn test_unsigned_wrapping_neg() {
+    assert_eq!(N8::from(0).wrapping_neg(), N8::from(0));
+    assert_eq!(N8::from(1).wrapping_neg(), N8::from(255));
+    assert_eq!(N8::MAX.wrapping_neg(), N8::from(1));
+    for i in 0..=255u8 {
+        assert_eq!(u8::from(N8::from(i).wrapping_neg()), i.wrapping_neg());
+    }
+    assert_eq!(u16::from(N16::from(1).wrapping_neg()), u16::MAX);
+    assert_eq!(u32::from(N32::from(u32::MAX).wrapping_neg()), 1);
+    assert_eq!(u64::from(N64::from(0).wrapping_neg()), 0);
+    assert_eq!(
+        u128::from(N128::from(5).wrapping_neg()),
+        5u128.wrapping_neg()
+    );
+    let (a, b) = (N32::from(1000), N32::from(1));
+    assert_eq!(a + b.wrapping_neg(), a - b);
+}
+
+#[test]
+fn test_signed_euclidean_division() {
+    assert_eq!(Z8::from(-7).rem_euclid(Z8::from(3)), Z8::from(2));
+    assert_eq!(Z8::from(-7).div_euclid(Z8::from(3)), Z8::from(-3));
+    assert_eq!(Z8::from(-7) % Z8::from(3), Z8::from(-1));
+    for (a, b) in [(7i32, 3i32), (-7, 3), (7, -3), (-7, -3), (6, 3), (-6, -3)] {
+        assert_eq!(
+            i32::from(Z32::from(a).div_euclid(Z32::from(b))),
+            a.div_euclid(b)
+        );
+        assert_eq!(
+            i32::from(Z32::from(a).rem_euclid(Z32::from(b))),
+            a.rem_euclid(b)
+        );
+        assert_eq!(
+            i16::from(Z16::from(a as i16).rem_euclid(Z16::from(b as i16))),
+            (a as i16).rem_euclid(b as i16)
+        );
+        assert_eq!(
+            i64::from(Z64::from(a as i64).div_euclid(Z64::from(b as i64))),
+            (a as i64).div_euclid(b as i64)
+        );
+    }
+    assert_eq!(Z8::MIN.div_euclid(Z8::from(-1)), Z8::MIN);
+}
+
+#[test]
+fn test_checked_div_rem_by_zero() {
+    assert_eq!(N8::from(10).checked_div(N8::from(0)), None);
+    assert_eq!(N8::from(10).checked_rem(N8::from(0)), None);
+    assert_eq!(N8::from(10).checked_div(N8::from(3)), Some(N8::from(3)));
+    assert_eq!(N8::from(10).checked_rem(N8::from(3)), Some(N8::from(1)));
+    assert_eq!(N16::from(1).checked_div(N16::from(0)), None);
+    assert_eq!(N32::from(9).checked_rem(N32::from(4)), Some(N32::from(1)));
+    assert_eq!(N64::from(1).checked_rem(N64::from(0)), None);
+    assert_eq!(N128::from(1).checked_div(N128::from(0)), None);
+    assert_eq!(Z32::from(-9).checked_div(Z32::from(0)), None);
+    assert_eq!(Z32::from(-9).checked_rem(Z32::from(4)), Some(Z32::from(-1)));
+}
+
+#[test]
+fn test_checked_div_rem_signed_overflow() {
+    assert_eq!(Z8::MIN.checked_div(Z8::from(-1)), None);
+    assert_eq!(Z8::MIN.checked_rem(Z8::from(-1)), None);
+    assert_eq!(Z16::MIN.checked_div(Z16::from(-1)), None);
+    assert_eq!(Z32::MIN.checked_div(Z32::from(-1)), None);
+    assert_eq!(Z64::MIN.checked_rem(Z64::from(-1)), None);
+    assert_eq!(Z128::MIN.checked_div(Z128::from(-1)), None);
+    assert_eq!(Z8::MIN.checked_div(Z8::from(1)), Some(Z8::MIN));
+}
+
+#[test]
+fn test_byte_nibble_packing() {
+    let byte = Byte::from_nibbles(Nibble::from(0x4), Nibble::from(0x2));
+    assert_eq!(u8::from(N8::from(byte)), 0x42);
+    assert_eq!(byte.to_nibbles(), (Nibble::from(0x4), Nibble::from(0x2)));
+    for value in 0..=255u8 {
+        let byte = Byte::from(N8::from(value));
+        let (high, low) = byte.to_nibbles();
+        assert_eq!(Byte::from_nibbles(high, low), byte);
+    }
+}
+
+#[test]
+fn test_nibble_is_valid_bcd() {
+    for value in 0..16u8 {
+        assert_eq!(Nibble::from(value).is_valid_bcd(), Bool::from(value <= 9));
+    }
+}
+
+#[test]
+fn test_from_hex_str() {
+    assert_eq!(N8::from_hex_str("0xFF"), Ok(N8::from(255)));
+    assert_eq!(N8::from_hex_str("a5"), Ok(N8::from(0xA5)));
+    assert_eq!(N8::from_hex_str("0X0001"), Ok(N8::from(1)));
+    assert_eq!(N16::from_hex_str("0xBEEF"), Ok(N16::from(0xBEEF)));
+    assert_eq!(N32::from_hex_str("DEADBEEF"), Ok(N32::from(0xDEAD_BEEF)));
+    assert_eq!(
+        N64::from_hex_str("0x0123456789abcdef"),
+        Ok(N64::from(0x0123_4567_89AB_CDEF))
+    );
+    assert_eq!(N128::from_hex_str("0x1").map(u128::from), Ok(1));
+}
+
+#[test]
+fn test_from_hex_str_errors() {
+    assert_eq!(
+        N8::from_hex_str("0x100"),
+        Err(ParseBitsError::TooLong { len: 3 })
+    );
+    assert_eq!(
+        N16::from_hex_str("1FFFF"),
+        Err(ParseBitsError::TooLong { len: 5 })
+    );
+    assert_eq!(
+        N8::from_hex_str("0xG1"),
+        Err(ParseBitsError::InvalidChar { ch: 'G', index: 2 })
+    );
+    assert!(N32::from_hex_str("12 34").is_err());
+}
+
+#[test]
+fn test_bit_range() {
+    assert_eq!(N32::from(0x0000_AB00).bit_range(8, 8), N32::from(0xAB));
+    assert_eq!(N32::from(0x0000_AB00).bit_range(4, 8), N32::from(0xB0));
+    assert_eq!(N8::from(0b1101_0110).bit_range(0, 8), N8::from(0b1101_0110));
+    assert_eq!(N8::from(0xFF).bit_range(3, 0), N8::from(0));
+    assert_eq!(N64::from(u64::MAX).bit_range(60, 4), N64::from(0xF));
+    assert_eq!(Z16::from(-1).bit_range(12, 4), Z16::from(0xF));
+}
+
+#[test]
+#[should_panic(expected = "bit range 4..12 out of range for 8 bits")]
+fn test_bit_range_out_of_range() {
+    let _ = N8::from(0).bit_range(4, 8);
+}
+
+#[test]
+fn test_integer_bit_mutation() {
+    let mut n = N8::from(0);
+    n.toggle_bit(0);
+    assert_eq!(n, N8::from(1));
+    n.toggle_bit(0);
+    assert_eq!(n, N8::from(0));
+
+    let mut max = N8::MAX;
+    max.clear_bit(7);
+    assert_eq!(max, N8::from(0x7F));
+    max.set_bit(7, Bit::One);
+    assert_eq!(max, N8::MAX);
+
+    assert_eq!(N32::from(0).with_bit(31, Bit::One), N32::from(1 << 31));
+    assert_eq!(N64::from(3).with_bit(0, Bit::Zero), N64::from(2));
+    let mut z = Z16::from(0);
+    z.set_bit(15, Bit::One);
+    assert_eq!(z, Z16::MIN);
+}
+
+#[test]
+#[should_panic(expected = "bit index 16 out of range for 16 bits")]
+fn test_integer_bit_mutation_out_of_range() {
+    let mut n = N16::from(0);
+    n.toggle_bit(16);
+}
+
+#[test]
+fn test_min_max_clamp() {
+    let (lo, hi) = (N8::from(10), N8::from(20));
+    assert_eq!(N8::from(5).clamp(lo, hi), lo);
+    assert_eq!(N8::from(25).clamp(lo, hi), hi);
+    assert_eq!(N8::from(15).clamp(lo, hi), N8::from(15));
+    assert_eq!(lo.min(hi), lo);
+    assert_eq!(lo.max(hi), hi);
+    assert_eq!(
+        Z32::from(-50).clamp(Z32::from(-10), Z32::from(10)),
+        Z32::from(-10)
+    );
+    assert_eq!(Z32::from(-5).max(Z32::from(-7)), Z32::from(-5));
+    assert_eq!(N64::from(7).min(N64::from(3)), N64::from(3));
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_inverted_bounds() {
+    let _ = N16::from(5).clamp(N16::from(10), N16::from(1));
+}
+
+#[test]
+fn test_sum_and_product() {
+    let values: Vec<N8> = [10, 20, 30].into_iter().map(N8::from).collect();
+    assert_eq!(values.iter().sum::<N8>(), N8::from(60));
+    assert_eq!(values.iter().copied().sum::<N8>(), N8::from(60));
+    assert_eq!(
+        values.iter().product::<N8>(),
+        N8::from((6000u32 % 256) as u8)
+    );
+    let small = [N32::from(2), N32::from(3), N32::from(7)];
+    assert_eq!(small.iter().product::<N32>(), N32::from(42));
+    assert_eq!(small.into_iter().sum::<N32>(), N32::from(12));
+    assert_eq!(
+        [N16::from(300), N16::from(300)].iter().product::<N16>(),
+        N16::from(24464)
+    );
+    assert_eq!(
+        [Z64::from(-2), Z64::from(5)].iter().product::<Z64>(),
+        Z64::from(-10)
+    );
+    assert_eq!(std::iter::empty::<Z8>().sum::<Z8>(), Z8::from(0));
+    assert_eq!(std::iter::empty::<N64>().product::<N64>(), N64::from(1));
+}
+
+#[test]
+fn test_signed_bit_count() {
+    assert_eq!(Z8::from(-1).count_ones(), 8);
+    assert_eq!(Z8::from(-1).count_zeros(), 0);
+    assert_eq!(Z8::MIN.count_ones(), 1);
+    assert_eq!(Z16::from(0).count_zeros(), 16);
+    assert_eq!(Z32::from(-2).count_ones(), (-2i32).count_ones());
+    assert_eq!(Z64::from(i64::MAX).count_zeros(), 1);
+    assert_eq!(Z128::from(-1).count_ones(), 128);
+}
+
+#[test]
+fn test_signed_unsigned_reinterpretation() {
+    assert_eq!(Z8::from(N8::from(255)), Z8::from(-1));
+    assert_eq!(Z8::from(N8::from(128)), Z8::from(-128));
+    assert_eq!(N8::from(Z8::from(-1)), N8::from(255));
+    assert_eq!(N8::from(Z8::from(-128)), N8::from(128));
+    assert_eq!(Z16::from(N16::from(0x8000)), Z16::MIN);
+    assert_eq!(N32::from(Z32::from(-2)), N32::from(u32::MAX - 1));
+    assert_eq!(Z64::from(N64::from(u64::MAX)), Z64::from(-1));
+    assert_eq!(N128::from(Z128::from(-1)), N128::from(u128::MAX));
+    assert_eq!(Z32::from(N32::from(12345)), Z32::from(12345));
+}
+
+#[test]
+fn test_byte_and_nibble_value() {
+    let byte = Byte::from(N8::from(0xA5));
+    assert_eq!(byte.value(), 165);
+    assert_eq!(format!("{}", byte), "10100101");
+    assert_eq!(format!("{:b}", byte), "10100101");
+    let nibble = Nibble::from(0b1010);
+    assert_eq!(nibble.value(), 10);
+    assert_eq!(format!("{}", nibble), "1010");
+}
+
+#[test]
+fn test_byte_bitwise_rotate_trait() {
+    let mut byte = Byte::from(N8::from(0b1000_0001));
+    rotate_twice(&mut byte);
+    assert_eq!(byte.value(), 0b1000_0001u8.rotate_left(2));
+    assert_eq!(byte.rotate_left(1).value(), 0b0000_1100);
+    BitwiseRotate::rotate_right(&mut byte, 9);
+    assert_eq!(byte.value(), 0b0000_0011);
+}
+
+#[test]
+fn test_byte_bit_count() {
+    let byte = Byte::from(N8::from(0b1011_0001));
+    assert_eq!(byte.count_ones(), 4);
+    assert_eq!(byte.count_zeros(), 4);
+}
+
+#[test]
+fn test_nibble_rotate_reverse_count() {
+    assert_eq!(Nibble::from(0b0001).rotate_left(1), Nibble::from(0b0010));
+    assert_eq!(Nibble::from(0b1000).rotate_left(1), Nibble::from(0b0001));
+    assert_eq!(Nibble::from(0b0001).rotate_right(5), Nibble::from(0b1000));
+    let mut nibble = Nibble::from(0b1000);
+    nibble.reverse_bits();
+    assert_eq!(nibble, Nibble::from(0b0001));
+    assert_eq!(Nibble::from(0b0110).reversed_bits(), Nibble::from(0b0110));
+    let mut rotated = Nibble::from(0b0011);
+    rotate_twice(&mut rotated);
+    assert_eq!(rotated, Nibble::from(0b1100));
+    assert_eq!(Nibble::from(0b1011).count_ones(), 3);
+    assert_eq!(Nibble::from(0b1011).count_zeros(), 1);
+}
+
+#[test]
+fn test_bool_comparators() {
+    let (a, b) = (N8::from(3), N8::from(7));
+    assert_eq!(a.eq_bool(&b), Bool::False);
+    assert_eq!(a.eq_bool(&a), Bool::True);
+    assert_eq!(a.lt_bool(&b), Bool::True);
+    assert_eq!(b.lt_bool(&a), Bool::False);
+    assert_eq!(a.le_bool(&a), Bool::True);
+    assert_eq!(b.le_bool(&a), Bool::False);
+    assert_eq!(b.gt_bool(&a), Bool::True);
+    assert_eq!(a.gt_bool(&a), Bool::False);
+    assert_eq!(a.ge_bool(&a), Bool::True);
+    assert_eq!(a.ge_bool(&b), Bool::False);
+    assert_eq!(Z16::from(-5).lt_bool(&Z16::from(2)), Bool::True);
+    assert!(a < b);
+    // The `PartialOrd` methods are not shadowed.
+    assert!(a.lt(&b));
+    assert!(!b.le(&a));
+}
+
+#[test]
+fn test_n8_compare_gates_exhaustive() {
+    for a in 0..=255u8 {
+        for b in 0..=255u8 {
+            let (lt, eq, gt) = N8::from(a).compare_gates(&N8::from(b));
+            let expected = a.cmp(&b);
+            assert_eq!(lt, Bool::from(expected == std::cmp::Ordering::Less));
+            assert_eq!(eq, Bool::from(expected == std::cmp::Ordering::Equal));
+            assert_eq!(gt, Bool::from(expected == std::cmp::Ordering::Greater));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_z8_div_by_zero_panics() {
+    let _ = Z8::from(1) / Z8::from(0);
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn test_z8_rem_by_zero_panics() {
+    let _ = Z8::from(1) % Z8::from(0);
+}
+
+#[test]
+#[should_panic]
+fn test_z32_div_euclid_by_zero_panics() {
+    let _ = Z32::from(7).div_euclid(Z32::from(0));
+}
+
+#[test]
+#[should_panic]
+fn test_z64_rem_euclid_by_zero_panics() {
+    let _ = Z64::from(7).rem_euclid(Z64::from(0));
+}
+
+#[test]
+fn test_z8_min_div_neg_one_wraps() {
+    assert_eq!(Z8::MIN / Z8::from(-1), Z8::MIN);
+    assert_eq!(Z8::MIN % Z8::from(-1), Z8::from(0));
+}
+
+#[test]
+fn test_debug_shows_decimal_and_binary() {
+    assert_eq!(format!("{:?}", N8::from(170)), "N8(170 = 0b10101010)");
+    assert_eq!(format!("{:?}", N8::from(1)), "N8(1 = 0b00000001)");
+    assert_eq!(
+        format!("{:?}", N16::from(0x0102)),
+        "N16(258 = 0b0000000100000010)"
+    );
+    assert_eq!(format!("{:?}", Z8::from(-1)), "Z8(-1 = 0b11111111)");
+    assert_eq!(
+        format!("{:?}", Some(Z8::from(5))),
+        "Some(Z8(5 = 0b00000101))"
+    );
+}
+
+#[test]
+fn test_from_bool_array() {
+    let flags = [true, false, true, false, false, false, false, false];
+    assert_eq!(N8::from(flags), N8::from(0b101));
+    assert_eq!(Byte::from(flags).value(), 0b101);
+    let mut wide = [false; 16];
+    wide[15] = true;
+    assert_eq!(N16::from(wide), N16::from(0x8000));
+    assert_eq!(N32::from([true; 32]), N32::from(u32::MAX));
+    assert_eq!(N64::from([false; 64]), N64::from(0));
+    let mut top = [false; 128];
+    top[127] = true;
+    assert_eq!(N128::from(top), N128::from(1u128 << 127));
+}
+
+#[test]
+fn test_collect_bits_into_wide_types() {
+    let value = N16::from(0xA5C3);
+    let collected: N16 = value.into_iter().collect();
+    assert_eq!(collected, value);
+    let short: N32 = [Bit::One, Bit::One].into_iter().collect();
+    assert_eq!(short, N32::from(3));
+    let long: N16 = N32::from(0xFFFF_1234).into_iter().collect();
+    assert_eq!(long, N16::from(0x1234));
+    let all: N64 = [Bit::One; 64].into_iter().collect();
+    assert_eq!(all, N64::from(u64::MAX));
+    let wide: N128 = N128::from(u128::MAX - 1).into_iter().collect();
+    assert_eq!(wide, N128::from(u128::MAX - 1));
+}
+
+#[test]
+fn test_set_bit_indices() {
+    assert_eq!(
+        N8::from(0b1001_0010).set_bit_indices().collect::<Vec<_>>(),
+        [1, 4, 7]
+    );
+    assert_eq!(N16::from(0).set_bit_indices().count(), 0);
+    assert_eq!(
+        N128::from(1u128 << 127 | 1)
+            .set_bit_indices()
+            .collect::<Vec<_>>(),
+        [0, 127]
+    );
+    assert_eq!(Z8::from(i8::MIN).set_bit_indices().collect::<Vec<_>>(), [7]);
+    assert_eq!(Z32::from(-1).set_bit_indices().count(), 32);
+}
+
+#[test]
+fn test_wrapping_shifts_mask_the_amount() {
+    let value = N16::from(0x1234);
+    assert_eq!(value.wrapping_shl(16), value);
+    assert_eq!(value.wrapping_shl(17), N16::from(0x2468));
+    assert_eq!(value.wrapping_shr(16), value);
+    assert_eq!(value.wrapping_shr(17), N16::from(0x091A));
+    let value = N32::from(1);
+    assert_eq!(value.wrapping_shl(32), value);
+    assert_eq!(value.wrapping_shl(33), N32::from(2));
+    let value = N64::from(u64::MAX);
+    assert_eq!(value.wrapping_shr(64), value);
+    assert_eq!(value.wrapping_shr(65), N64::from(u64::MAX >> 1));
+    assert_eq!(N8::from(0x81).wrapping_shl(9), N8::from(0x02));
+    assert_eq!(N128::from(2).wrapping_shr(129), N128::from(1));
+    assert_eq!(Z8::from(-128).wrapping_shr(9), Z8::from(-64));
+    assert_eq!(Z32::from(-1).wrapping_shl(32), Z32::from(-1));
+    // The bit-array shifts on Byte clear instead of wrapping.
+    assert_eq!((Byte::from(N8::from(0xFF)) << 8).value(), 0);
+}
+
+#[test]
+fn test_n8_shift_by_width_or_more_is_zero() {
+    let value = N8::from(0b1011_0001);
+    assert_eq!(value << 8, N8::ZERO);
+    assert_eq!(value << 10, N8::ZERO);
+    assert_eq!(value >> 8, N8::ZERO);
+    assert_eq!(value >> 10, N8::ZERO);
+    assert_eq!(value << 3, N8::from(0b1000_1000));
+    assert_eq!(value >> 3, N8::from(0b0001_0110));
+    let mut shifted = value;
+    shifted <<= 200;
+    assert_eq!(shifted, N8::ZERO);
+    for shift in 0..=255u8 {
+        let byte = Byte::from(value);
+        assert_eq!(Byte::from(value << shift), byte << shift);
+        assert_eq!(Byte::from(value >> shift), byte >> shift);
+    }
+}
+
+#[test]
+fn test_n16_shift_by_width_or_more_is_zero() {
+    let value = N16::from(0xB00F);
+    assert_eq!(value << 16, N16::ZERO);
+    assert_eq!(value << 17, N16::ZERO);
+    assert_eq!(value >> 16, N16::ZERO);
+    assert_eq!(value >> 17, N16::ZERO);
+    assert_eq!(value << 4, N16::from(0x00F0));
+    assert_eq!(value >> 4, N16::from(0x0B00));
+    for shift in 0..16u8 {
+        assert_eq!(u16::from(value << shift), 0xB00F << shift);
+        assert_eq!(u16::from(value >> shift), 0xB00F >> shift);
+    }
+}
+
+#[test]
+fn test_n8_overflowing_sub_reports_borrow() {
+    assert_eq!(
+        N8::from(0).overflowing_sub(N8::from(1)),
+        (N8::from(255), Bool::True)
+    );
+    assert_eq!(
+        N8::from(200).overflowing_sub(N8::from(55)),
+        (N8::from(145), Bool::False)
+    );
+    assert_eq!(N8::from(0).checked_sub(N8::from(1)), None);
+    assert_eq!(N8::from(7).checked_sub(N8::from(7)), Some(N8::from(0)));
+    assert_eq!(N8::from(0) - N8::from(1), N8::from(255));
+}
+
+#[test]
+fn test_pointer_sized_conversions() {
+    let items: Vec<char> = "abcd".chars().collect();
+    assert_eq!(items[N32::from(2).to_usize()], 'c');
+    assert_eq!(items[N64::from(3).to_usize()], 'd');
+    assert_eq!(Z32::from(-5).to_isize(), -5);
+    assert_eq!(usize::try_from(N32::from(7)), Ok(7));
+    assert_eq!(N32::try_from(7usize), Ok(N32::from(7)));
+    assert_eq!(Z32::try_from(-1isize), Ok(Z32::from(-1)));
+    assert_eq!(isize::try_from(Z64::from(-9)), Ok(-9));
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert_eq!(Z64::from(i64::MIN).to_isize() as i64, i64::MIN);
+        assert_eq!(N64::try_from(usize::MAX), Ok(N64::from(usize::MAX as u64)));
+        assert_eq!(N32::try_from(1usize << 32), Err(ConversionError));
+        assert_eq!(Z32::try_from(isize::MIN), Err(ConversionError));
+    }
+}
+
+#[test]
+fn test_primitive_accessors_match_from() {
+    let n = N8::from(200);
+    assert_eq!(n.to_u8(), u8::from(n));
+    assert_eq!(N16::from(0xBEEF).to_u16(), 0xBEEF);
+    assert_eq!(N32::from(7).wrapping_add(N32::from(1)).to_u32(), 8);
+    assert_eq!(N64::from(u64::MAX).to_u64(), u64::MAX);
+    assert_eq!(N128::from(1u128 << 100).to_u128(), 1u128 << 100);
+    assert_eq!(Z8::from(-3).to_i8(), i8::from(Z8::from(-3)));
+    assert_eq!(Z16::from(-300).to_i16(), -300);
+    assert_eq!(Z32::from(i32::MIN).to_i32(), i32::MIN);
+    assert_eq!(Z64::from(-1).to_i64(), -1);
+    assert_eq!(Z128::from(i128::MAX).to_i128(), i128::MAX);
+    assert_eq!(R16::from(1.5).to_f32(), f32::from(R16::from(1.5)));
+    assert_eq!(R32::from(2.25).to_f32(), 2.25);
+    assert_eq!(R64::from(-0.5).to_f64(), -0.5);
+}
+
+#[test]
+fn test_bit_reductions() {
+    assert_eq!(N8::MAX.reduce_and(), Bit::One);
+    assert_eq!(N8::ZERO.reduce_or(), Bit::Zero);
+    assert_eq!(N8::from(0xFE).reduce_and(), Bit::Zero);
+    assert_eq!(N16::from(0x0100).reduce_or(), Bit::One);
+    assert_eq!(N32::from(0b111).reduce_xor(), Bit::One);
+    assert_eq!(N64::from(0b11).reduce_xor(), Bit::Zero);
+    assert_eq!(Z8::from(-1).reduce_and(), Bit::One);
+    assert_eq!(Z128::from(0).reduce_or(), Bit::Zero);
+    for value in 0..=255u8 {
+        let n = N8::from(value);
+        assert_eq!(n.reduce_xor(), n.parity());
+    }
+}
+
+#[test]
+fn test_cmp_popcount() {
+    use std::cmp::Ordering;
+    assert_eq!(
+        N8::from(0b1110).cmp_popcount(&N8::from(0b0001)),
+        Ordering::Greater
+    );
+    // Numerically smaller but with more ones.
+    assert_eq!(
+        N8::from(0b0111).cmp_popcount(&N8::from(0b1000_0000)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        N16::from(0x00FF).cmp_popcount(&N16::from(0xFF00)),
+        Ordering::Equal
+    );
+    assert_eq!(Z8::from(0).cmp_popcount(&Z8::from(-1)), Ordering::Less);
+    let mut population = [N8::from(0xFF), N8::from(1), N8::from(0b1010)];
+    population.sort_by(|a, b| a.cmp_popcount(b));
+    assert_eq!(population, [N8::from(1), N8::from(0b1010), N8::from(0xFF)]);
+}
+
+#[test]
+fn test_const_bitwise_ops() {
+    const LOW: N8 = N8::from_u8(0x0F);
+    const ODD: N8 = N8::from_u8(0b0101_0101);
+    const AND: N8 = LOW.bitand_const(ODD);
+    const OR: N8 = LOW.bitor_const(ODD);
+    const XOR: N8 = LOW.bitxor_const(ODD);
+    const HIGH: N8 = LOW.not_const();
+    assert_eq!(AND, N8::from(0x05));
+    assert_eq!(OR, N8::from(0x5F));
+    assert_eq!(XOR, N8::from(0x5A));
+    assert_eq!(HIGH, N8::from(0xF0));
+
+    const MASK: N32 = N32::from_u32(0xFFFF_0000).bitxor_const(N32::from_u32(0x0F0F_0F0F));
+    assert_eq!(MASK, N32::from(0xF0F0_0F0F));
+    const WIDE: N128 = N128::from_u128(0)
+        .not_const()
+        .bitand_const(N128::from_u128(42));
+    assert_eq!(WIDE, N128::from(42));
+    assert_eq!(
+        N16::from_u16(0x1200).bitor_const(N16::from_u16(0x0034)),
+        N16::from(0x1234)
+    );
+    assert_eq!(N64::from_u64(u64::MAX).not_const(), N64::from(0));
+}
+
+#[test]
+fn test_n8_add_either() {
+    assert_eq!(
+        N8::from(100).add_either(N8::from(55)),
+        Either::Left(N8::from(155))
+    );
+    assert_eq!(
+        N8::from(200).add_either(N8::from(100)),
+        Either::Right((N8::from(44), Bit::One))
+    );
+    assert_eq!(N8::MAX.add_either(N8::ZERO), Either::Left(N8::MAX));
+    let sum = N8::from(255)
+        .add_either(N8::from(1))
+        .either(|n| n, |(n, _)| n);
+    assert_eq!(sum, N8::ZERO);
+}
+
+#[test]
+fn test_bit_chars_and_byte_parsing() {
+    assert_eq!(Bit::One.to_char(), '1');
+    assert_eq!(Bit::Zero.to_char(), '0');
+    assert_eq!(Bit::from_char('1'), Ok(Bit::One));
+    assert_eq!(
+        Bit::from_char('x'),
+        Err(ParseBitsError::InvalidChar { ch: 'x', index: 0 })
+    );
+
+    let byte = Byte::try_from("10101010").unwrap();
+    assert_eq!(byte.to_string(), "10101010");
+    assert_eq!(byte.value(), 0b1010_1010);
+    assert_eq!(
+        Byte::try_from("101"),
+        Err(ParseBitsError::TooShort { len: 3 })
+    );
+    assert_eq!(
+        Byte::try_from("101010101"),
+        Err(ParseBitsError::TooLong { len: 9 })
+    );
+    assert_eq!(
+        Byte::try_from("1010_101"),
+        Err(ParseBitsError::InvalidChar { ch: '_', index: 4 })
+    );
+
+    let from_chars = Byte::from_chars("00001111".chars()).unwrap();
+    assert_eq!(from_chars.value(), 0x0F);
+    let rendered: String = byte.iter_bits().rev().map(|b| b.to_char()).collect();
+    assert_eq!(Byte::from_chars(rendered.chars()), Ok(byte));
+    assert_eq!(
+        Byte::from_chars("111111111".chars()),
+        Err(ParseBitsError::TooLong { len: 9 })
+    );
+    assert_eq!(
+        Byte::from_chars("12".chars()),
+        Err(ParseBitsError::InvalidChar { ch: '2', index: 1 })
+    );
+    assert_eq!(
+        Byte::from_chars("0101".chars()),
+        Err(ParseBitsError::TooShort { len: 4 })
+    );
+    assert_eq!(Byte::from_chars("".chars()), Err(ParseBitsError::Empty));
+    let mut input = "111111111x".chars();
+    assert_eq!(
+        Byte::from_chars(input.by_ref()),
+        Err(ParseBitsError::TooLong { len: 9 })
+    );
+    assert_eq!(input.next(), Some('x'));
+    assert_eq!(
+        ParseBitsError::TooShort { len: 3 }.to_string(),
+        "input of 3 digits is too short for the target type"
+    );
+}
+
+#[test]
+fn test_leading_and_trailing_ones() {
+    let n = N8::from(0b1111_0000);
+    assert_eq!(n.leading_ones(), 4);
+    assert_eq!(n.trailing_ones(), 0);
+    for value in 0..=255u8 {
+        let n = N8::from(value);
+        assert_eq!(n.leading_ones(), value.leading_ones());
+        assert_eq!(n.trailing_ones(), value.trailing_ones());
+    }
+    assert_eq!(N16::from(0x00FF).trailing_ones(), 8);
+    assert_eq!(N32::from(u32::MAX).leading_ones(), 32);
+    assert_eq!(N64::from(0b0111).trailing_ones(), 3);
+    assert_eq!(N128::from(u128::MAX << 1).leading_ones(), 127);
+    assert_eq!(Z8::from(-2).leading_ones(), 7);
+    assert_eq!(Z16::from(-1).trailing_ones(), 16);
+    assert_eq!(Z32::from(i32::MAX).leading_ones(), 0);
+    assert_eq!(Z64::from(3).trailing_ones(), 2);
+    assert_eq!(Z128::from(i128::MIN).leading_ones(), 1);
+}
+
+#[test]
+fn test_z64_to_z32_is_fallible() {
+    assert_eq!(Z32::try_from(Z64::from(-123_456)), Ok(Z32::from(-123_456)));
+    assert_eq!(
+        Z32::try_from(Z64::from(i32::MIN as i64)),
+        Ok(Z32::from(i32::MIN))
+    );
+    assert_eq!(Z32::try_from(Z64::from(i64::MAX)), Err(ConversionError));
+    assert_eq!(
+        Z32::try_from(Z64::from(i32::MIN as i64 - 1)),
+        Err(ConversionError)
+    );
+}
+
+#[test]
+fn test_signed_shifts_by_width_or_more() {
+    assert_eq!(Z8::from(-37) << 8, Z8::from(0));
+    assert_eq!(Z8::from(-37) << 9, Z8::from(0));
+    assert_eq!(Z8::from(-37) >> 8, Z8::from(-1));
+    assert_eq!(Z8::from(-37) >> 9, Z8::from(-1));
+    assert_eq!(Z8::from(37) >> 8, Z8::from(0));
+    assert_eq!(Z16::from(-300) << 16, Z16::from(0));
+    assert_eq!(Z16::from(-300) >> 17, Z16::from(-1));
+    assert_eq!(Z32::from(12345) << 33, Z32::from(0));
+    assert_eq!(Z32::from(12345) >> 32, Z32::from(0));
+    assert_eq!(Z64::from(-1) << 64, Z64::from(0));
+    assert_eq!(Z64::from(i64::MIN) >> 65, Z64::from(-1));
+    assert_eq!(Z128::from(5) << 128, Z128::from(0));
+    assert_eq!(Z128::from(-5) >> 129, Z128::from(-1));
+    for shift in 0..8u8 {
+        for value in [i8::MIN, -37, -1, 0, 1, 37, i8::MAX] {
+            assert_eq!(i8::from(Z8::from(value) << shift), value << shift);
+            assert_eq!(i8::from(Z8::from(value) >> shift), value >> shift);
+        }
+    }
+}
+
+#[test]
+fn test_parse_bits_rejects_empty_input() {
+    assert_eq!("".parse::<N8>(), Err(ParseBitsError::Empty));
+    assert_eq!("".parse::<N128>(), Err(ParseBitsError::Empty));
+    assert_eq!(Byte::try_from(""), Err(ParseBitsError::Empty));
+}
+
+#[test]
+fn test_from_hex_str_requires_digits() {
+    assert_eq!(N8::from_hex_str("0x"), Err(ParseBitsError::Empty));
+    assert_eq!(N8::from_hex_str("0X"), Err(ParseBitsError::Empty));
+    assert_eq!(N8::from_hex_str(""), Err(ParseBitsError::Empty));
+    assert_eq!(N32::from_hex_str("0x"), Err(ParseBitsError::Empty));
+    assert_eq!(N8::from_hex_str("0x0"), Ok(N8::from(0)));
+    assert_eq!(ParseBitsError::Empty.to_string(), "no digits in input");
+}
+
+#[test]
+fn test_n8_bitxor() {
+    assert_eq!(N8::from(0b1100) ^ N8::from(0b1010), N8::from(0b0110));
+    assert_eq!(N8::from(0xFF) ^ N8::from(0xFF), N8::from(0));
+}
+
+#[test]
+#[should_panic(expected = "bit index 8 out of range for 8 bits")]
+fn test_with_bit_out_of_range() {
+    let _ = Z8::from(0).with_bit(8, Bit::One);
+}
+
+#[test]
+fn test_nuint_shift_beyond_u8_range() {
+    let one = Nuint::<300>::from(1u128);
+    let top = one << 299;
+    assert_eq!(top.count_ones(), 1);
+    assert_eq!(top >> 299, one);
+    assert_eq!(one << 300, Nuint::<300>::from(0u128));
+}
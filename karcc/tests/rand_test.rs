@@ -0,0 +1,34 @@
+#![cfg(feature = "rand")]
+
+use karcc::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+#[test]
+fn test_random_integers_cover_the_range() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut seen_high = false;
+    let mut seen_negative = false;
+    for _ in 0..1000 {
+        let n: N8 = rng.r#gen();
+        seen_high |= u8::from(n) >= 128;
+        let z: Z16 = rng.r#gen();
+        seen_negative |= i16::from(z) < 0;
+    }
+    assert!(seen_high);
+    assert!(seen_negative);
+    let _: N32 = rand::random();
+    let _: (N64, N128, Z8, Z32, Z64, Z128) = rng.r#gen();
+}
+
+#[test]
+fn test_random_floats_are_in_unit_interval() {
+    let mut rng = StdRng::seed_from_u64(11);
+    for _ in 0..1000 {
+        let a = f32::from(rng.r#gen::<R16>());
+        let b = f32::from(rng.r#gen::<R32>());
+        let c = f64::from(rng.r#gen::<R64>());
+        assert!((0.0..1.0).contains(&a), "{}", a);
+        assert!((0.0..1.0).contains(&b), "{}", b);
+        assert!((0.0..1.0).contains(&c), "{}", c);
+    }
+}
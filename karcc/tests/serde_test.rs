@@ -0,0 +1,45 @@
+#![cfg(feature = "serde")]
+
+use karcc::*;
+
+#[test]
+fn test_n8_serde_round_trip() {
+    let n = N8::from(200);
+    let json = serde_json::to_string(&n).unwrap();
+    assert_eq!(json, "200");
+    assert_eq!(serde_json::from_str::<N8>(&json).unwrap(), n);
+}
+
+#[test]
+fn test_n8_serde_rejects_out_of_range() {
+    assert!(serde_json::from_str::<N8>("256").is_err());
+    assert!(serde_json::from_str::<N8>("-1").is_err());
+}
+
+#[test]
+fn test_signed_and_float_serde_round_trip() {
+    let z = Z32::from(-123_456);
+    assert_eq!(serde_json::to_string(&z).unwrap(), "-123456");
+    assert_eq!(serde_json::from_str::<Z32>("-123456").unwrap(), z);
+    assert!(serde_json::from_str::<Z8>("128").is_err());
+    let r = R64::from(1.5);
+    assert_eq!(serde_json::to_string(&r).unwrap(), "1.5");
+    assert_eq!(serde_json::from_str::<R64>("1.5").unwrap(), r);
+}
+
+#[test]
+fn test_bit_level_types_serde() {
+    assert_eq!(serde_json::to_string(&Bit::One).unwrap(), "1");
+    assert_eq!(serde_json::from_str::<Bit>("0").unwrap(), Bit::Zero);
+    assert!(serde_json::from_str::<Bit>("2").is_err());
+    assert_eq!(serde_json::to_string(&Bool::True).unwrap(), "true");
+    assert_eq!(serde_json::from_str::<Bool>("false").unwrap(), Bool::False);
+    assert_eq!(
+        serde_json::from_str::<Nibble>("15").unwrap(),
+        Nibble::from(15)
+    );
+    assert!(serde_json::from_str::<Nibble>("16").is_err());
+    let byte = Byte::from(N8::from(0xA5));
+    assert_eq!(serde_json::to_string(&byte).unwrap(), "165");
+    assert_eq!(serde_json::from_str::<Byte>("165").unwrap(), byte);
+}